@@ -0,0 +1,9 @@
+use crate::api::models::{Agent, ApiResponse};
+use crate::api::SpaceTradersClient;
+use anyhow::Result;
+
+impl SpaceTradersClient {
+    pub async fn get_agent_details(&self) -> Result<ApiResponse<Agent>> {
+        self.get("/my/agent").await
+    }
+}
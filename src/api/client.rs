@@ -0,0 +1,373 @@
+use super::error::SpaceTradersError;
+use super::models::Paginated;
+use super::rate_limit::{RateLimit, RateLimiter};
+use super::retry::RetryPolicy;
+use anyhow::{Context, Result};
+use dotenv::dotenv;
+use futures_util::{stream, Stream, TryStreamExt};
+use log::{debug, error, warn};
+use reqwest::{Client, Method, RequestBuilder, Response};
+use serde::{de::DeserializeOwned, Serialize};
+use std::env;
+use std::sync::Arc;
+
+pub const DEFAULT_BASE_URL: &str = "https://api.spacetraders.io/v2";
+
+/// Largest page size the list endpoints accept.
+pub const MAX_PAGE_LIMIT: u32 = 20;
+
+/// Environment variable that overrides [`DEFAULT_BASE_URL`] in [`SpaceTradersClient::from_env`].
+pub const BASE_URL_ENV: &str = "SPACETRADERS_BASE_URL";
+
+/// Shared handle to the SpaceTraders API.
+///
+/// Owns a single `reqwest::Client` so connections are pooled across calls.
+/// Cloning is cheap, so one client can be handed to several agent tasks;
+/// clones share the same rate limiter.
+#[derive(Debug, Clone)]
+pub struct SpaceTradersClient {
+    http: Client,
+    token: String,
+    base_url: String,
+    rate_limiter: Arc<RateLimiter>,
+    retry_policy: RetryPolicy,
+}
+
+impl SpaceTradersClient {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            http: Client::new(),
+            token: token.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            rate_limiter: Arc::new(RateLimiter::new(RateLimit::default())),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Builds a client from `BEARER_TOKEN`, loading `.env` first if present.
+    ///
+    /// `SPACETRADERS_BASE_URL` points the client at a staging deployment or a
+    /// local mock server instead of the public API.
+    pub fn from_env() -> Result<Self> {
+        dotenv().ok();
+        let token = env::var("BEARER_TOKEN").context("BEARER_TOKEN must be set in .env")?;
+        Ok(Self::new(token).with_env_base_url())
+    }
+
+    /// Builds a client for account-level calls such as `register`, using the
+    /// optional `ACCOUNT_TOKEN` instead of an agent token.
+    pub fn account_from_env() -> Self {
+        dotenv().ok();
+        let token = env::var("ACCOUNT_TOKEN").unwrap_or_default();
+        Self::new(token).with_env_base_url()
+    }
+
+    fn with_env_base_url(self) -> Self {
+        match env::var(BASE_URL_ENV) {
+            Ok(base_url) if !base_url.trim().is_empty() => self.with_base_url(base_url),
+            _ => self,
+        }
+    }
+
+    /// Sends every request to `base_url` instead of [`DEFAULT_BASE_URL`].
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Replaces the default SpaceTraders limits, e.g. for a mock server.
+    ///
+    /// Clones made before this call keep sharing the previous limiter.
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limiter = Arc::new(RateLimiter::new(limit));
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Swaps in a new token, e.g. the agent token returned by `register`.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = token.into();
+        self
+    }
+
+    /// Starts a request against `path`, relative to the base URL. The token is
+    /// attached unless the client was built without one.
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request = self
+            .http
+            .request(method, format!("{}{}", self.base_url, path));
+
+        if self.token.is_empty() {
+            request
+        } else {
+            request.header("Authorization", format!("Bearer {}", self.token))
+        }
+    }
+
+    pub(crate) async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self.send(self.request(Method::GET, path)).await
+    }
+
+    pub(crate) async fn post<T, B>(&self, path: &str, body: &B) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize + ?Sized,
+    {
+        self.send(self.request(Method::POST, path).json(body)).await
+    }
+
+    pub(crate) async fn patch<T, B>(&self, path: &str, body: &B) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize + ?Sized,
+    {
+        self.send(self.request(Method::PATCH, path).json(body))
+            .await
+    }
+
+    pub(crate) async fn get_page<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        page: u32,
+        limit: u32,
+    ) -> Result<Paginated<T>> {
+        self.get_page_with_query(path, &[], page, limit).await
+    }
+
+    /// Fetches one page, sending `query` alongside the page and limit parameters.
+    pub(crate) async fn get_page_with_query<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        page: u32,
+        limit: u32,
+    ) -> Result<Paginated<T>> {
+        let request = self
+            .request(Method::GET, path)
+            .query(query)
+            .query(&[("page", page), ("limit", limit)]);
+        self.send(request).await
+    }
+
+    /// Streams every item of a list endpoint, fetching pages as they are consumed.
+    pub(crate) fn paginate<'a, T>(&'a self, path: String) -> impl Stream<Item = Result<T>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+    {
+        self.paginate_with_query(path, Vec::new())
+    }
+
+    /// Like `paginate`, sending `query` with every page request.
+    pub(crate) fn paginate_with_query<'a, T>(
+        &'a self,
+        path: String,
+        query: Vec<(&'static str, String)>,
+    ) -> impl Stream<Item = Result<T>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+    {
+        stream::try_unfold(Some(1), move |page| {
+            let path = path.clone();
+            let query = query.clone();
+            async move {
+                let Some(page) = page else {
+                    return Ok::<_, anyhow::Error>(None);
+                };
+                let response: Paginated<T> = self
+                    .get_page_with_query(&path, &query, page, MAX_PAGE_LIMIT)
+                    .await?;
+                let next_page = response.meta.has_next_page().then_some(page + 1);
+                let items = stream::iter(response.data.into_iter().map(Ok));
+                Ok(Some((items, next_page)))
+            }
+        })
+        .try_flatten()
+    }
+
+    /// Collects every page of a list endpoint.
+    pub(crate) async fn get_all<T: DeserializeOwned>(&self, path: &str) -> Result<Vec<T>> {
+        self.get_all_with_query(path, Vec::new()).await
+    }
+
+    /// Collects every page of a list endpoint, sending `query` with each request.
+    pub(crate) async fn get_all_with_query<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: Vec<(&'static str, String)>,
+    ) -> Result<Vec<T>> {
+        self.paginate_with_query(path.to_string(), query)
+            .try_collect()
+            .await
+    }
+
+    /// Sends the request, waiting for a rate limit permit before every attempt
+    /// and retrying transient failures according to the retry policy.
+    pub(crate) async fn execute(&self, request: RequestBuilder) -> Result<Response> {
+        let request = request.build()?;
+        let mut attempt = 0;
+
+        loop {
+            // JSON bodies are buffered, so the request can always be cloned.
+            let attempt_request = request
+                .try_clone()
+                .context("request body cannot be retried")?;
+
+            self.rate_limiter.acquire().await;
+            let result = self.http.execute(attempt_request).await;
+
+            let delay = match &result {
+                Ok(response) => {
+                    self.retry_policy
+                        .delay_for_response(response, request.method(), attempt)
+                }
+                Err(e) => self
+                    .retry_policy
+                    .delay_for_error(e, request.method(), attempt),
+            };
+
+            match delay {
+                Some(delay) => {
+                    match &result {
+                        Ok(response) => warn!(
+                            "{} {} returned {}, retrying in {:?}",
+                            request.method(),
+                            request.url(),
+                            response.status(),
+                            delay
+                        ),
+                        Err(e) => warn!(
+                            "{} {} failed: {}, retrying in {:?}",
+                            request.method(),
+                            request.url(),
+                            e,
+                            delay
+                        ),
+                    }
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return Ok(result?),
+            }
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let response = self.execute(request).await?;
+
+        let status = response.status();
+        debug!("{} {}", status, response.url());
+        let response_text = response.text().await?;
+        debug!("Raw response: {}", response_text);
+
+        if !status.is_success() {
+            let error = SpaceTradersError::from_response(status.as_u16(), &response_text);
+            error!("{}", error);
+            if let Some(data) = error.detail().and_then(|detail| detail.data.as_ref()) {
+                debug!("Error data: {:?}", data);
+            }
+            return Err(error.into());
+        }
+
+        serde_json::from_str(&response_text).map_err(|e| {
+            error!("Failed to parse response: {}", e);
+            error!("Response text was: {}", response_text);
+            e.into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serves `total` numbered items from any path, `limit` per page, and
+    /// records which pages were requested.
+    async fn serve_items(total: u32) -> (String, Arc<Mutex<Vec<u32>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requested = Arc::new(Mutex::new(Vec::new()));
+
+        let log = requested.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buffer = vec![0; 4096];
+                let read = socket.read(&mut buffer).await.unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]).to_string();
+
+                let query = |name: &str| -> u32 {
+                    let target = request.split_whitespace().nth(1).unwrap();
+                    target
+                        .split(['?', '&'])
+                        .find_map(|pair| pair.strip_prefix(&format!("{}=", name)))
+                        .unwrap()
+                        .parse()
+                        .unwrap()
+                };
+                let (page, limit) = (query("page"), query("limit"));
+                log.lock().unwrap().push(page);
+
+                let first = (page - 1) * limit;
+                let items: Vec<u32> = (first..total.min(first + limit)).collect();
+                let body = serde_json::json!({
+                    "data": items,
+                    "meta": { "total": total, "page": page, "limit": limit },
+                })
+                .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base_url, requested)
+    }
+
+    async fn fetch_all(total: u32) -> (Vec<u32>, Vec<u32>) {
+        let (base_url, requested) = serve_items(total).await;
+        let client = SpaceTradersClient::new("token").with_base_url(base_url);
+
+        let items: Vec<u32> = client.get_all("/items").await.unwrap();
+        let pages = requested.lock().unwrap().clone();
+        (items, pages)
+    }
+
+    #[tokio::test]
+    async fn get_all_fetches_a_partial_last_page() {
+        let (items, pages) = fetch_all(45).await;
+        assert_eq!(items, (0..45).collect::<Vec<_>>());
+        assert_eq!(pages, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn get_all_stops_after_an_exact_multiple_of_the_limit() {
+        let (items, pages) = fetch_all(40).await;
+        assert_eq!(items, (0..40).collect::<Vec<_>>());
+        assert_eq!(pages, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn get_all_handles_an_empty_list() {
+        let (items, pages) = fetch_all(0).await;
+        assert!(items.is_empty());
+        assert_eq!(pages, vec![1]);
+    }
+}
@@ -1,94 +1,93 @@
-use serde::Deserialize;
-use crate::api::ApiResponse;
+use crate::api::models::{
+    AcceptContractResponse, ApiResponse, Contract, DeliverContractResponse,
+    FulfillContractResponse, NegotiateContractResponse, Paginated, TradeSymbol,
+};
+use crate::api::SpaceTradersClient;
+use futures_util::Stream;
+use log::debug;
+use serde::Serialize;
 
-#[derive(Debug, Deserialize)]
-pub struct Contract {
-    pub id: String,
-    #[serde(rename = "factionSymbol")]
-    pub faction_symbol: String,
-    #[serde(rename = "type")]
-    pub contract_type: String,
-    pub terms: ContractTerms,
-    pub accepted: bool,
-    pub fulfilled: bool,
-    #[serde(rename = "deadlineToAccept")]
-    pub deadline_to_accept: String,
+#[derive(Debug, Serialize)]
+pub struct DeliverContractRequest {
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: String,
+    #[serde(rename = "tradeSymbol")]
+    pub trade_symbol: TradeSymbol,
+    pub units: i32,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ContractTerms {
-    pub deadline: String,
-    pub payment: ContractPayment,
-    pub deliver: Option<Vec<ContractDelivery>>,
-}
+impl SpaceTradersClient {
+    /// Fetches every contract, following pagination.
+    pub async fn list_contracts(&self) -> anyhow::Result<Vec<Contract>> {
+        self.get_all("/my/contracts").await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct ContractPayment {
-    #[serde(rename = "onAccepted")]
-    pub on_accepted: i32,
-    #[serde(rename = "onFulfilled")]
-    pub on_fulfilled: i32,
-}
+    pub async fn list_contracts_page(
+        &self,
+        page: u32,
+        limit: u32,
+    ) -> anyhow::Result<Paginated<Contract>> {
+        self.get_page("/my/contracts", page, limit).await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct ContractDelivery {
-    #[serde(rename = "tradeSymbol")]
-    pub trade_symbol: String,
-    #[serde(rename = "destinationSymbol")]
-    pub destination_symbol: String,
-    #[serde(rename = "unitsRequired")]
-    pub units_required: i32,
-    #[serde(rename = "unitsFulfilled")]
-    pub units_fulfilled: i32,
-}
+    pub fn contracts_stream(&self) -> impl Stream<Item = anyhow::Result<Contract>> + '_ {
+        self.paginate("/my/contracts".to_string())
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct AcceptContractResponse {
-    pub contract: Contract,
-    pub agent: super::api::Agent,
-}
+    pub async fn accept_contract(
+        &self,
+        contract_id: &str,
+    ) -> anyhow::Result<ApiResponse<AcceptContractResponse>> {
+        self.post(
+            &format!("/my/contracts/{}/accept", contract_id),
+            &serde_json::json!({}),
+        )
+        .await
+    }
 
-pub async fn list_contracts() -> anyhow::Result<ApiResponse<Vec<Contract>>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .get("https://api.spacetraders.io/v2/my/contracts")
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?
-        .json()
-        .await?;
+    /// Hands over cargo from a ship docked at the delivery destination.
+    pub async fn deliver_contract(
+        &self,
+        contract_id: &str,
+        ship_symbol: &str,
+        trade_symbol: &TradeSymbol,
+        units: i32,
+    ) -> anyhow::Result<ApiResponse<DeliverContractResponse>> {
+        debug!(
+            "Delivering {} units of {} from ship {} for contract {}",
+            units, trade_symbol, ship_symbol, contract_id
+        );
+        let request = DeliverContractRequest {
+            ship_symbol: ship_symbol.to_string(),
+            trade_symbol: trade_symbol.clone(),
+            units,
+        };
 
-    Ok(response)
-}
+        self.post(&format!("/my/contracts/{}/deliver", contract_id), &request)
+            .await
+    }
 
-pub async fn accept_contract(contract_id: &str) -> anyhow::Result<ApiResponse<AcceptContractResponse>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let url = format!("https://api.spacetraders.io/v2/my/contracts/{}/accept", contract_id);
-    
-    println!("Making request to: {}", url);
-    
-    let response = client
-        .post(&url)
-        .header("Authorization", format!("Bearer {}", token))
-        .header("Content-Type", "application/json")
-        .json(&serde_json::json!({}))  // Send empty JSON object
-        .send()
-        .await?;
-    
-    println!("Response status: {}", response.status());
-    
-    let response_text = response.text().await?;
-    println!("Raw response: {}", response_text);
-    
-    let parsed_response: ApiResponse<AcceptContractResponse> = serde_json::from_str(&response_text)
-        .map_err(|e| {
-            println!("JSON parsing error: {}", e);
-            e
-        })?;
-    
-    Ok(parsed_response)
-} 
\ No newline at end of file
+    /// Completes a contract once every delivery is met and collects the payment.
+    pub async fn fulfill_contract(
+        &self,
+        contract_id: &str,
+    ) -> anyhow::Result<ApiResponse<FulfillContractResponse>> {
+        self.post(
+            &format!("/my/contracts/{}/fulfill", contract_id),
+            &serde_json::json!({}),
+        )
+        .await
+    }
+
+    /// Asks the faction at the ship's waypoint for a new contract.
+    pub async fn negotiate_contract(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<NegotiateContractResponse>> {
+        self.post(
+            &format!("/my/ships/{}/negotiate/contract", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
+    }
+}
@@ -0,0 +1,268 @@
+use super::models::ShipCooldown;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: ErrorDetail,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorDetail {
+    pub message: String,
+    pub code: i32,
+    pub data: Option<serde_json::Value>,
+}
+
+impl ErrorDetail {
+    /// Decodes the error's `data` payload into a concrete type.
+    pub fn data_as<T: DeserializeOwned>(&self) -> Option<T> {
+        self.data
+            .as_ref()
+            .and_then(|data| serde_json::from_value(data.clone()).ok())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CooldownErrorData {
+    cooldown: ShipCooldown,
+}
+
+/// Failures reported by the SpaceTraders API, keyed on the error `code`.
+///
+/// Endpoint methods return `anyhow::Result`; automation can recover the
+/// variant with `err.downcast_ref::<SpaceTradersError>()` and match on it.
+#[derive(Debug)]
+pub enum SpaceTradersError {
+    /// 4000: the ship's reactor is still cooling down.
+    Cooldown {
+        message: String,
+        cooldown: ShipCooldown,
+    },
+    /// 4200, 4214: the ship is in transit and cannot act yet.
+    ShipInTransit(ErrorDetail),
+    /// 4203: not enough fuel to reach the destination.
+    InsufficientFuel(ErrorDetail),
+    /// 4217: the requested units exceed what the ship can hold.
+    CargoExceedsLimit(ErrorDetail),
+    /// 4218: the ship does not carry the requested cargo.
+    CargoMissing(ErrorDetail),
+    /// 4227: the ship lacks the mounts this action needs.
+    MissingMounts(ErrorDetail),
+    /// 4220, 4221, 4224: the survey failed verification, expired or is exhausted.
+    SurveyUnusable(ErrorDetail),
+    /// 4228: the ship's cargo hold is full.
+    CargoFull(ErrorDetail),
+    /// 4236: the action requires the ship to be in orbit.
+    ShipNotInOrbit(ErrorDetail),
+    /// 4244: the action requires the ship to be docked.
+    ShipNotDocked(ErrorDetail),
+    /// 4216, 4600: the agent cannot afford the purchase.
+    InsufficientCredits(ErrorDetail),
+    /// 4601, 4602: the market does not buy or sell this good.
+    GoodNotTraded(ErrorDetail),
+    /// 4604: the trade exceeds the market's per-transaction volume.
+    TradeVolumeExceeded(ErrorDetail),
+    /// 4113 or any 401: the token was rejected, usually because the server
+    /// reset since it was issued and the agent must register again.
+    TokenInvalid(ErrorDetail),
+    /// 429: the server rejected the request for exceeding the rate limit.
+    RateLimited(ErrorDetail),
+    /// Any other error code returned by the API.
+    Api(ErrorDetail),
+    /// A non-success response whose body was not an API error.
+    Http { status: u16, body: String },
+}
+
+impl SpaceTradersError {
+    pub fn from_detail(detail: ErrorDetail) -> Self {
+        match detail.code {
+            4000 => match detail.data_as::<CooldownErrorData>() {
+                Some(data) => Self::Cooldown {
+                    message: detail.message,
+                    cooldown: data.cooldown,
+                },
+                None => Self::Api(detail),
+            },
+            4200 | 4214 => Self::ShipInTransit(detail),
+            4203 => Self::InsufficientFuel(detail),
+            4217 => Self::CargoExceedsLimit(detail),
+            4218 => Self::CargoMissing(detail),
+            4227 => Self::MissingMounts(detail),
+            4220 | 4221 | 4224 => Self::SurveyUnusable(detail),
+            4228 => Self::CargoFull(detail),
+            4236 => Self::ShipNotInOrbit(detail),
+            4244 => Self::ShipNotDocked(detail),
+            4216 | 4600 => Self::InsufficientCredits(detail),
+            4601 | 4602 => Self::GoodNotTraded(detail),
+            4604 => Self::TradeVolumeExceeded(detail),
+            4113 => Self::TokenInvalid(detail),
+            429 => Self::RateLimited(detail),
+            _ => Self::Api(detail),
+        }
+    }
+
+    /// Builds the error for a failed response, decoding the body when it is an `ErrorResponse`.
+    pub fn from_response(status: u16, body: &str) -> Self {
+        match serde_json::from_str::<ErrorResponse>(body) {
+            Ok(response) if status == 401 => Self::TokenInvalid(response.error),
+            Ok(response) => Self::from_detail(response.error),
+            Err(_) => Self::Http {
+                status,
+                body: body.to_string(),
+            },
+        }
+    }
+
+    pub fn detail(&self) -> Option<&ErrorDetail> {
+        match self {
+            Self::ShipInTransit(detail)
+            | Self::InsufficientFuel(detail)
+            | Self::CargoExceedsLimit(detail)
+            | Self::CargoMissing(detail)
+            | Self::MissingMounts(detail)
+            | Self::SurveyUnusable(detail)
+            | Self::CargoFull(detail)
+            | Self::ShipNotInOrbit(detail)
+            | Self::ShipNotDocked(detail)
+            | Self::InsufficientCredits(detail)
+            | Self::GoodNotTraded(detail)
+            | Self::TradeVolumeExceeded(detail)
+            | Self::TokenInvalid(detail)
+            | Self::RateLimited(detail)
+            | Self::Api(detail) => Some(detail),
+            Self::Cooldown { .. } | Self::Http { .. } => None,
+        }
+    }
+
+    /// Whether the server refused the token, including 401s without an API error body.
+    pub fn is_token_invalid(&self) -> bool {
+        matches!(self, Self::TokenInvalid(_) | Self::Http { status: 401, .. })
+    }
+
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            Self::Cooldown { .. } => Some(4000),
+            _ => self.detail().map(|detail| detail.code),
+        }
+    }
+}
+
+impl fmt::Display for SpaceTradersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cooldown { message, cooldown } => write!(
+                f,
+                "API Error: {} ({} seconds remaining)",
+                message, cooldown.remaining_seconds
+            ),
+            Self::Http { status, body } => {
+                write!(f, "Request failed with status {}: {}", status, body)
+            }
+            _ => match self.detail() {
+                Some(detail) => write!(f, "API Error: {} (Code: {})", detail.message, detail.code),
+                None => write!(f, "API Error"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for SpaceTradersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(code: i32) -> ErrorDetail {
+        ErrorDetail {
+            message: "error".to_string(),
+            code,
+            data: None,
+        }
+    }
+
+    #[test]
+    fn cooldown_data_is_decoded() {
+        let body = r#"{"error":{"message":"Ship is on cooldown","code":4000,"data":{"cooldown":{"shipSymbol":"SHIP-1","totalSeconds":70,"remainingSeconds":42,"expiration":"2023-06-10T15:39:26.000Z"}}}}"#;
+        match SpaceTradersError::from_response(409, body) {
+            SpaceTradersError::Cooldown { message, cooldown } => {
+                assert_eq!(message, "Ship is on cooldown");
+                assert_eq!(cooldown.ship_symbol, "SHIP-1");
+                assert_eq!(cooldown.remaining_seconds, 42);
+            }
+            other => panic!("expected Cooldown, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn cooldown_without_usable_data_falls_back_to_api() {
+        let body =
+            r#"{"error":{"message":"Ship is on cooldown","code":4000,"data":{"cooldown":"soon"}}}"#;
+        let error = SpaceTradersError::from_response(409, body);
+        assert!(matches!(error, SpaceTradersError::Api(_)), "{:?}", error);
+        assert_eq!(error.code(), Some(4000));
+    }
+
+    #[test]
+    fn unauthorized_api_errors_are_token_invalid() {
+        let body = r#"{"error":{"message":"Failed to parse token","code":401}}"#;
+        let error = SpaceTradersError::from_response(401, body);
+        assert!(
+            matches!(error, SpaceTradersError::TokenInvalid(_)),
+            "{:?}",
+            error
+        );
+        assert!(error.is_token_invalid());
+    }
+
+    #[test]
+    fn non_json_bodies_become_http_errors() {
+        let error = SpaceTradersError::from_response(502, "Bad Gateway");
+        match &error {
+            SpaceTradersError::Http { status, body } => {
+                assert_eq!(*status, 502);
+                assert_eq!(body, "Bad Gateway");
+            }
+            other => panic!("expected Http, got {:?}", other),
+        }
+        assert_eq!(error.code(), None);
+        assert!(!error.is_token_invalid());
+    }
+
+    #[test]
+    fn codes_map_to_their_variants() {
+        use SpaceTradersError::*;
+
+        let cases = [
+            ShipInTransit(detail(4200)),
+            ShipInTransit(detail(4214)),
+            InsufficientFuel(detail(4203)),
+            CargoExceedsLimit(detail(4217)),
+            CargoMissing(detail(4218)),
+            MissingMounts(detail(4227)),
+            SurveyUnusable(detail(4220)),
+            CargoFull(detail(4228)),
+            ShipNotInOrbit(detail(4236)),
+            ShipNotDocked(detail(4244)),
+            InsufficientCredits(detail(4600)),
+            GoodNotTraded(detail(4601)),
+            TradeVolumeExceeded(detail(4604)),
+            TokenInvalid(detail(4113)),
+            RateLimited(detail(429)),
+            Api(detail(9999)),
+        ];
+
+        for expected in cases {
+            let code = expected.code().unwrap();
+            let error = SpaceTradersError::from_detail(detail(code));
+            assert_eq!(
+                std::mem::discriminant(&error),
+                std::mem::discriminant(&expected),
+                "code {} became {:?}",
+                code,
+                error
+            );
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::api::models::{AgentFaction, ApiResponse, Faction, FactionSymbol, Paginated};
+use crate::api::SpaceTradersClient;
+use anyhow::Result;
+
+impl SpaceTradersClient {
+    /// Fetches every faction in the universe, following pagination.
+    pub async fn list_factions(&self) -> Result<Vec<Faction>> {
+        self.get_all("/factions").await
+    }
+
+    pub async fn list_factions_page(&self, page: u32, limit: u32) -> Result<Paginated<Faction>> {
+        self.get_page("/factions", page, limit).await
+    }
+
+    pub async fn get_faction(
+        &self,
+        faction_symbol: &FactionSymbol,
+    ) -> Result<ApiResponse<Faction>> {
+        self.get(&format!("/factions/{}", faction_symbol)).await
+    }
+
+    /// The agent's reputation with every faction, following pagination.
+    pub async fn get_my_factions(&self) -> Result<Vec<AgentFaction>> {
+        self.get_all("/my/factions").await
+    }
+
+    /// The agent's reputation with one faction, or `None` if the server does not list it.
+    pub async fn get_reputation(&self, faction_symbol: &FactionSymbol) -> Result<Option<i32>> {
+        let factions = self.get_my_factions().await?;
+        Ok(factions
+            .into_iter()
+            .find(|faction| &faction.symbol == faction_symbol)
+            .map(|faction| faction.reputation))
+    }
+}
@@ -1,32 +1,40 @@
-// In src/api/mod.rs
-mod api;
-mod cargo_management;
+mod agent;
+mod client;
 mod contracts;
+mod error;
+mod factions;
+pub mod models;
+mod rate_limit;
+mod register;
+mod retry;
 mod ships;
 mod shipyard;
+mod status;
+mod systems;
 
 // Public re-exports
 pub use self::{
-    // From api module
-    api::{
-        get_agent_details, get_waypoint_details, Agent, ApiResponse, Waypoint, WaypointFaction,
-        WaypointOrbital, WaypointTrait,
-    },
-    // From cargo_management module
-    cargo_management::{
-        analyze_markets, calculate_max_sellable_units, find_all_markets_in_system,
-        get_contract_requirements, ContractRequirement, MarketAnalysis, MarketPriceInfo,
-    },
+    // From client module
+    client::{SpaceTradersClient, BASE_URL_ENV, DEFAULT_BASE_URL, MAX_PAGE_LIMIT},
     // From contracts module
-    contracts::{accept_contract, list_contracts, AcceptContractResponse, Contract},
+    contracts::DeliverContractRequest,
+    // From error module
+    error::{ErrorDetail, ErrorResponse, SpaceTradersError},
+    // From models module
+    models::*,
+    // From rate_limit module
+    rate_limit::{RateLimit, RateLimiter},
+    // From register module
+    register::{save_token, RegisterRequest, DEFAULT_ENV_FILE},
+    // From retry module
+    retry::RetryPolicy,
     // From ships module
     ships::{
-        dock_ship, extract_resources, find_asteroids, get_market_data, get_ship_cargo, list_ships,
-        navigate_ship, orbit_ship, refuel_ship, sell_cargo_item, CargoItem, CargoResponse,
-        DockResponse, ExtractionResponse, Market, MarketTransaction, NavigationResponse, Ship,
+        FlightModeRequest, JettisonRequest, NavigateRequest, PurchaseCargoRequest,
+        PurchaseShipRequest, RefineRequest, SellCargoRequest, TransferCargoRequest,
     },
     // From shipyard module
-    shipyard::{
-        find_shipyards, get_shipyard_details, purchase_ship, Ship as ShipyardShip, Shipyard,
-    },
+    shipyard::ShipComponentRequest,
+    // From systems module
+    systems::{system_symbol_of, SupplyConstructionRequest, WaypointFilters},
 };
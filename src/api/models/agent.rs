@@ -0,0 +1,49 @@
+use super::{Contract, FactionSymbol, Ship};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Agent {
+    #[serde(rename = "accountId")]
+    pub account_id: String,
+    pub symbol: String,
+    pub headquarters: String,
+    pub credits: i64,
+    #[serde(rename = "startingFaction")]
+    pub starting_faction: FactionSymbol,
+    #[serde(rename = "shipCount")]
+    pub ship_count: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Faction {
+    pub symbol: FactionSymbol,
+    pub name: String,
+    pub description: String,
+    pub headquarters: String,
+    pub traits: Vec<FactionTrait>,
+    #[serde(rename = "isRecruiting")]
+    pub is_recruiting: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionTrait {
+    pub symbol: String,
+    pub name: String,
+    pub description: String,
+}
+
+/// The agent's standing with one faction, as listed by `/my/factions`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentFaction {
+    pub symbol: FactionSymbol,
+    pub reputation: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterResponse {
+    pub agent: Agent,
+    pub contract: Contract,
+    pub faction: Faction,
+    pub ship: Ship,
+    pub token: String,
+}
@@ -0,0 +1,36 @@
+use super::{ShipCargo, TradeSymbol};
+use serde::{Deserialize, Serialize};
+
+/// A waypoint under construction, such as an unfinished jump gate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Construction {
+    /// The waypoint being built.
+    pub symbol: String,
+    pub materials: Vec<ConstructionMaterial>,
+    #[serde(rename = "isComplete")]
+    pub is_complete: bool,
+}
+
+impl Construction {
+    /// Materials that still need units, paired with how many are missing.
+    pub fn outstanding_materials(&self) -> impl Iterator<Item = (&ConstructionMaterial, i32)> {
+        self.materials
+            .iter()
+            .map(|material| (material, material.required - material.fulfilled))
+            .filter(|(_, remaining)| *remaining > 0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstructionMaterial {
+    #[serde(rename = "tradeSymbol")]
+    pub trade_symbol: TradeSymbol,
+    pub required: i32,
+    pub fulfilled: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplyConstructionResponse {
+    pub construction: Construction,
+    pub cargo: ShipCargo,
+}
@@ -0,0 +1,93 @@
+use super::{Agent, FactionSymbol, ShipCargo, TradeSymbol};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contract {
+    pub id: String,
+    #[serde(rename = "factionSymbol")]
+    pub faction_symbol: FactionSymbol,
+    #[serde(rename = "type")]
+    pub contract_type: String,
+    pub terms: ContractTerms,
+    pub accepted: bool,
+    pub fulfilled: bool,
+    /// When the offer lapses if it has not been accepted.
+    pub expiration: DateTime<Utc>,
+    #[serde(rename = "deadlineToAccept")]
+    pub deadline_to_accept: DateTime<Utc>,
+}
+
+impl Contract {
+    /// Whether the contract can still be accepted (if open) or completed (if accepted) at `now`.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        if self.fulfilled {
+            return false;
+        }
+        if self.accepted {
+            now < self.terms.deadline
+        } else {
+            now < self.deadline_to_accept
+        }
+    }
+
+    /// Deliveries that still need units, paired with how many are missing.
+    pub fn outstanding_deliveries(&self) -> impl Iterator<Item = (&ContractDelivery, i32)> {
+        self.terms
+            .deliver
+            .iter()
+            .flatten()
+            .map(|delivery| (delivery, delivery.units_required - delivery.units_fulfilled))
+            .filter(|(_, remaining)| *remaining > 0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractTerms {
+    pub deadline: DateTime<Utc>,
+    pub payment: ContractPayment,
+    pub deliver: Option<Vec<ContractDelivery>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractPayment {
+    #[serde(rename = "onAccepted")]
+    pub on_accepted: i32,
+    #[serde(rename = "onFulfilled")]
+    pub on_fulfilled: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractDelivery {
+    #[serde(rename = "tradeSymbol")]
+    pub trade_symbol: TradeSymbol,
+    #[serde(rename = "destinationSymbol")]
+    pub destination_symbol: String,
+    #[serde(rename = "unitsRequired")]
+    pub units_required: i32,
+    #[serde(rename = "unitsFulfilled")]
+    pub units_fulfilled: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcceptContractResponse {
+    pub contract: Contract,
+    pub agent: Agent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliverContractResponse {
+    pub contract: Contract,
+    pub cargo: ShipCargo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FulfillContractResponse {
+    pub contract: Contract,
+    pub agent: Agent,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NegotiateContractResponse {
+    pub contract: Contract,
+}
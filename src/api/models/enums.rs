@@ -0,0 +1,426 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Declares a string-valued API enum.
+///
+/// Values the server adds after this client was written decode into
+/// `Unknown` instead of failing the whole response.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            /// A value this client does not know about yet.
+            Unknown(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    other => Self::Unknown(other.to_string()),
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok(Self::from(value))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                Ok(Self::from(value.as_str()))
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Where a ship is relative to its current waypoint.
+    ShipNavStatus {
+        InTransit => "IN_TRANSIT",
+        InOrbit => "IN_ORBIT",
+        Docked => "DOCKED",
+    }
+}
+
+string_enum! {
+    /// Trades travel speed against fuel consumption.
+    FlightMode {
+        Drift => "DRIFT",
+        Stealth => "STEALTH",
+        Cruise => "CRUISE",
+        Burn => "BURN",
+    }
+}
+
+string_enum! {
+    /// The factions an agent can start with, sign contracts for and earn reputation with.
+    FactionSymbol {
+        Cosmic => "COSMIC",
+        Void => "VOID",
+        Galactic => "GALACTIC",
+        Quantum => "QUANTUM",
+        Dominion => "DOMINION",
+        Astro => "ASTRO",
+        Corsairs => "CORSAIRS",
+        Obsidian => "OBSIDIAN",
+        Aegis => "AEGIS",
+        United => "UNITED",
+        Solitary => "SOLITARY",
+        Cobalt => "COBALT",
+        Omega => "OMEGA",
+        Echo => "ECHO",
+        Lords => "LORDS",
+        Cult => "CULT",
+        Ancients => "ANCIENTS",
+        Shadow => "SHADOW",
+        Ethereal => "ETHEREAL",
+    }
+}
+
+string_enum! {
+    /// The role a ship was registered with.
+    ShipRole {
+        Fabricator => "FABRICATOR",
+        Harvester => "HARVESTER",
+        Hauler => "HAULER",
+        Interceptor => "INTERCEPTOR",
+        Excavator => "EXCAVATOR",
+        Transport => "TRANSPORT",
+        Repair => "REPAIR",
+        Surveyor => "SURVEYOR",
+        Command => "COMMAND",
+        Carrier => "CARRIER",
+        Patrol => "PATROL",
+        Satellite => "SATELLITE",
+        Explorer => "EXPLORER",
+        Refinery => "REFINERY",
+    }
+}
+
+string_enum! {
+    WaypointType {
+        Planet => "PLANET",
+        GasGiant => "GAS_GIANT",
+        Moon => "MOON",
+        OrbitalStation => "ORBITAL_STATION",
+        JumpGate => "JUMP_GATE",
+        AsteroidField => "ASTEROID_FIELD",
+        Asteroid => "ASTEROID",
+        EngineeredAsteroid => "ENGINEERED_ASTEROID",
+        AsteroidBase => "ASTEROID_BASE",
+        Nebula => "NEBULA",
+        DebrisField => "DEBRIS_FIELD",
+        GravityWell => "GRAVITY_WELL",
+        ArtificialGravityWell => "ARTIFICIAL_GRAVITY_WELL",
+        FuelStation => "FUEL_STATION",
+    }
+}
+
+string_enum! {
+    WaypointTraitSymbol {
+        Uncharted => "UNCHARTED",
+        UnderConstruction => "UNDER_CONSTRUCTION",
+        Marketplace => "MARKETPLACE",
+        Shipyard => "SHIPYARD",
+        Outpost => "OUTPOST",
+        ScatteredSettlements => "SCATTERED_SETTLEMENTS",
+        SprawlingCities => "SPRAWLING_CITIES",
+        MegaStructures => "MEGA_STRUCTURES",
+        PirateBase => "PIRATE_BASE",
+        Overcrowded => "OVERCROWDED",
+        HighTech => "HIGH_TECH",
+        Corrupt => "CORRUPT",
+        Bureaucratic => "BUREAUCRATIC",
+        TradingHub => "TRADING_HUB",
+        Industrial => "INDUSTRIAL",
+        BlackMarket => "BLACK_MARKET",
+        ResearchFacility => "RESEARCH_FACILITY",
+        MilitaryBase => "MILITARY_BASE",
+        SurveillanceOutpost => "SURVEILLANCE_OUTPOST",
+        ExplorationOutpost => "EXPLORATION_OUTPOST",
+        MineralDeposits => "MINERAL_DEPOSITS",
+        CommonMetalDeposits => "COMMON_METAL_DEPOSITS",
+        PreciousMetalDeposits => "PRECIOUS_METAL_DEPOSITS",
+        RareMetalDeposits => "RARE_METAL_DEPOSITS",
+        MethanePools => "METHANE_POOLS",
+        IceCrystals => "ICE_CRYSTALS",
+        ExplosiveGases => "EXPLOSIVE_GASES",
+        StrongMagnetosphere => "STRONG_MAGNETOSPHERE",
+        VibrantAuroras => "VIBRANT_AURORAS",
+        SaltFlats => "SALT_FLATS",
+        Canyons => "CANYONS",
+        PerpetualDaylight => "PERPETUAL_DAYLIGHT",
+        PerpetualOvercast => "PERPETUAL_OVERCAST",
+        DrySeabeds => "DRY_SEABEDS",
+        MagmaSeas => "MAGMA_SEAS",
+        Supervolcanoes => "SUPERVOLCANOES",
+        AshClouds => "ASH_CLOUDS",
+        VastRuins => "VAST_RUINS",
+        MutatedFlora => "MUTATED_FLORA",
+        Terraformed => "TERRAFORMED",
+        ExtremeTemperatures => "EXTREME_TEMPERATURES",
+        ExtremePressure => "EXTREME_PRESSURE",
+        DiverseLife => "DIVERSE_LIFE",
+        ScarceLife => "SCARCE_LIFE",
+        Fossils => "FOSSILS",
+        WeakGravity => "WEAK_GRAVITY",
+        StrongGravity => "STRONG_GRAVITY",
+        CrushingGravity => "CRUSHING_GRAVITY",
+        ToxicAtmosphere => "TOXIC_ATMOSPHERE",
+        CorrosiveAtmosphere => "CORROSIVE_ATMOSPHERE",
+        BreathableAtmosphere => "BREATHABLE_ATMOSPHERE",
+        ThinAtmosphere => "THIN_ATMOSPHERE",
+        Jovian => "JOVIAN",
+        Rocky => "ROCKY",
+        Volcanic => "VOLCANIC",
+        Frozen => "FROZEN",
+        Swamp => "SWAMP",
+        Barren => "BARREN",
+        Temperate => "TEMPERATE",
+        Jungle => "JUNGLE",
+        Ocean => "OCEAN",
+        Radioactive => "RADIOACTIVE",
+        MicroGravityAnomalies => "MICRO_GRAVITY_ANOMALIES",
+        DebrisCluster => "DEBRIS_CLUSTER",
+        DeepCraters => "DEEP_CRATERS",
+        ShallowCraters => "SHALLOW_CRATERS",
+        UnstableComposition => "UNSTABLE_COMPOSITION",
+        HollowedInterior => "HOLLOWED_INTERIOR",
+        Stripped => "STRIPPED",
+    }
+}
+
+string_enum! {
+    /// How much of a good a market has on hand.
+    SupplyLevel {
+        Scarce => "SCARCE",
+        Limited => "LIMITED",
+        Moderate => "MODERATE",
+        High => "HIGH",
+        Abundant => "ABUNDANT",
+    }
+}
+
+string_enum! {
+    /// How many extractions a survey supports before it is exhausted.
+    SurveySize {
+        Small => "SMALL",
+        Moderate => "MODERATE",
+        Large => "LARGE",
+    }
+}
+
+string_enum! {
+    /// Every good that can be carried, traded or delivered.
+    TradeSymbol {
+        PreciousStones => "PRECIOUS_STONES",
+        QuartzSand => "QUARTZ_SAND",
+        SiliconCrystals => "SILICON_CRYSTALS",
+        AmmoniaIce => "AMMONIA_ICE",
+        LiquidHydrogen => "LIQUID_HYDROGEN",
+        LiquidNitrogen => "LIQUID_NITROGEN",
+        IceWater => "ICE_WATER",
+        ExoticMatter => "EXOTIC_MATTER",
+        AdvancedCircuitry => "ADVANCED_CIRCUITRY",
+        GravitonEmitters => "GRAVITON_EMITTERS",
+        Iron => "IRON",
+        IronOre => "IRON_ORE",
+        Copper => "COPPER",
+        CopperOre => "COPPER_ORE",
+        Aluminum => "ALUMINUM",
+        AluminumOre => "ALUMINUM_ORE",
+        Silver => "SILVER",
+        SilverOre => "SILVER_ORE",
+        Gold => "GOLD",
+        GoldOre => "GOLD_ORE",
+        Platinum => "PLATINUM",
+        PlatinumOre => "PLATINUM_ORE",
+        Diamonds => "DIAMONDS",
+        Uranite => "URANITE",
+        UraniteOre => "URANITE_ORE",
+        Meritium => "MERITIUM",
+        MeritiumOre => "MERITIUM_ORE",
+        Hydrocarbon => "HYDROCARBON",
+        Antimatter => "ANTIMATTER",
+        FabMats => "FAB_MATS",
+        Fertilizers => "FERTILIZERS",
+        Fabrics => "FABRICS",
+        Food => "FOOD",
+        Jewelry => "JEWELRY",
+        Machinery => "MACHINERY",
+        Firearms => "FIREARMS",
+        AssaultRifles => "ASSAULT_RIFLES",
+        MilitaryEquipment => "MILITARY_EQUIPMENT",
+        Explosives => "EXPLOSIVES",
+        LabInstruments => "LAB_INSTRUMENTS",
+        Ammunition => "AMMUNITION",
+        Electronics => "ELECTRONICS",
+        ShipPlating => "SHIP_PLATING",
+        ShipParts => "SHIP_PARTS",
+        Equipment => "EQUIPMENT",
+        Fuel => "FUEL",
+        Medicine => "MEDICINE",
+        Drugs => "DRUGS",
+        Clothing => "CLOTHING",
+        Microprocessors => "MICROPROCESSORS",
+        Plastics => "PLASTICS",
+        Polynucleotides => "POLYNUCLEOTIDES",
+        Biocomposites => "BIOCOMPOSITES",
+        QuantumStabilizers => "QUANTUM_STABILIZERS",
+        Nanobots => "NANOBOTS",
+        AiMainframes => "AI_MAINFRAMES",
+        QuantumDrives => "QUANTUM_DRIVES",
+        RoboticDrones => "ROBOTIC_DRONES",
+        CyberImplants => "CYBER_IMPLANTS",
+        GeneTherapeutics => "GENE_THERAPEUTICS",
+        NeuralChips => "NEURAL_CHIPS",
+        MoodRegulators => "MOOD_REGULATORS",
+        ViralAgents => "VIRAL_AGENTS",
+        MicroFusionGenerators => "MICRO_FUSION_GENERATORS",
+        Supergrains => "SUPERGRAINS",
+        LaserRifles => "LASER_RIFLES",
+        Holographics => "HOLOGRAPHICS",
+        ShipSalvage => "SHIP_SALVAGE",
+        RelicTech => "RELIC_TECH",
+        NovelLifeforms => "NOVEL_LIFEFORMS",
+        BotanicalSpecimens => "BOTANICAL_SPECIMENS",
+        CulturalArtifacts => "CULTURAL_ARTIFACTS",
+        FrameProbe => "FRAME_PROBE",
+        FrameDrone => "FRAME_DRONE",
+        FrameInterceptor => "FRAME_INTERCEPTOR",
+        FrameRacer => "FRAME_RACER",
+        FrameFighter => "FRAME_FIGHTER",
+        FrameFrigate => "FRAME_FRIGATE",
+        FrameShuttle => "FRAME_SHUTTLE",
+        FrameExplorer => "FRAME_EXPLORER",
+        FrameMiner => "FRAME_MINER",
+        FrameLightFreighter => "FRAME_LIGHT_FREIGHTER",
+        FrameHeavyFreighter => "FRAME_HEAVY_FREIGHTER",
+        FrameTransport => "FRAME_TRANSPORT",
+        FrameDestroyer => "FRAME_DESTROYER",
+        FrameCruiser => "FRAME_CRUISER",
+        FrameCarrier => "FRAME_CARRIER",
+        FrameBulkFreighter => "FRAME_BULK_FREIGHTER",
+        ReactorSolarI => "REACTOR_SOLAR_I",
+        ReactorFusionI => "REACTOR_FUSION_I",
+        ReactorFissionI => "REACTOR_FISSION_I",
+        ReactorChemicalI => "REACTOR_CHEMICAL_I",
+        ReactorAntimatterI => "REACTOR_ANTIMATTER_I",
+        EngineImpulseDriveI => "ENGINE_IMPULSE_DRIVE_I",
+        EngineIonDriveI => "ENGINE_ION_DRIVE_I",
+        EngineIonDriveIi => "ENGINE_ION_DRIVE_II",
+        EngineHyperDriveI => "ENGINE_HYPER_DRIVE_I",
+        ModuleMineralProcessorI => "MODULE_MINERAL_PROCESSOR_I",
+        ModuleGasProcessorI => "MODULE_GAS_PROCESSOR_I",
+        ModuleCargoHoldI => "MODULE_CARGO_HOLD_I",
+        ModuleCargoHoldIi => "MODULE_CARGO_HOLD_II",
+        ModuleCargoHoldIii => "MODULE_CARGO_HOLD_III",
+        ModuleCrewQuartersI => "MODULE_CREW_QUARTERS_I",
+        ModuleEnvoyQuartersI => "MODULE_ENVOY_QUARTERS_I",
+        ModulePassengerCabinI => "MODULE_PASSENGER_CABIN_I",
+        ModuleMicroRefineryI => "MODULE_MICRO_REFINERY_I",
+        ModuleScienceLabI => "MODULE_SCIENCE_LAB_I",
+        ModuleJumpDriveI => "MODULE_JUMP_DRIVE_I",
+        ModuleJumpDriveIi => "MODULE_JUMP_DRIVE_II",
+        ModuleJumpDriveIii => "MODULE_JUMP_DRIVE_III",
+        ModuleWarpDriveI => "MODULE_WARP_DRIVE_I",
+        ModuleWarpDriveIi => "MODULE_WARP_DRIVE_II",
+        ModuleWarpDriveIii => "MODULE_WARP_DRIVE_III",
+        ModuleShieldGeneratorI => "MODULE_SHIELD_GENERATOR_I",
+        ModuleShieldGeneratorIi => "MODULE_SHIELD_GENERATOR_II",
+        ModuleOreRefineryI => "MODULE_ORE_REFINERY_I",
+        ModuleFuelRefineryI => "MODULE_FUEL_REFINERY_I",
+        MountGasSiphonI => "MOUNT_GAS_SIPHON_I",
+        MountGasSiphonIi => "MOUNT_GAS_SIPHON_II",
+        MountGasSiphonIii => "MOUNT_GAS_SIPHON_III",
+        MountSurveyorI => "MOUNT_SURVEYOR_I",
+        MountSurveyorIi => "MOUNT_SURVEYOR_II",
+        MountSurveyorIii => "MOUNT_SURVEYOR_III",
+        MountSensorArrayI => "MOUNT_SENSOR_ARRAY_I",
+        MountSensorArrayIi => "MOUNT_SENSOR_ARRAY_II",
+        MountSensorArrayIii => "MOUNT_SENSOR_ARRAY_III",
+        MountMiningLaserI => "MOUNT_MINING_LASER_I",
+        MountMiningLaserIi => "MOUNT_MINING_LASER_II",
+        MountMiningLaserIii => "MOUNT_MINING_LASER_III",
+        MountLaserCannonI => "MOUNT_LASER_CANNON_I",
+        MountMissileLauncherI => "MOUNT_MISSILE_LAUNCHER_I",
+        MountTurretI => "MOUNT_TURRET_I",
+        ShipProbe => "SHIP_PROBE",
+        ShipMiningDrone => "SHIP_MINING_DRONE",
+        ShipSiphonDrone => "SHIP_SIPHON_DRONE",
+        ShipInterceptor => "SHIP_INTERCEPTOR",
+        ShipLightHauler => "SHIP_LIGHT_HAULER",
+        ShipCommandFrigate => "SHIP_COMMAND_FRIGATE",
+        ShipExplorer => "SHIP_EXPLORER",
+        ShipHeavyFreighter => "SHIP_HEAVY_FREIGHTER",
+        ShipLightShuttle => "SHIP_LIGHT_SHUTTLE",
+        ShipOreHound => "SHIP_ORE_HOUND",
+        ShipRefiningFreighter => "SHIP_REFINING_FREIGHTER",
+        ShipSurveyor => "SHIP_SURVEYOR",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_values_round_trip_to_their_variant() {
+        let mode: FlightMode = serde_json::from_str(r#""CRUISE""#).unwrap();
+        assert_eq!(mode, FlightMode::Cruise);
+        assert_eq!(serde_json::to_string(&mode).unwrap(), r#""CRUISE""#);
+    }
+
+    #[test]
+    fn unknown_values_are_kept_and_serialized_back() {
+        let mode: FlightMode = serde_json::from_str(r#""WARP_SPEED""#).unwrap();
+        assert_eq!(mode, FlightMode::Unknown("WARP_SPEED".to_string()));
+        assert_eq!(serde_json::to_string(&mode).unwrap(), r#""WARP_SPEED""#);
+    }
+
+    #[test]
+    fn from_str_and_display_agree() {
+        for value in ["IN_ORBIT", "DOCKED", "SOMEWHERE_ELSE"] {
+            let status = ShipNavStatus::from(value);
+            assert_eq!(status.to_string(), value);
+            assert_eq!(value.parse::<ShipNavStatus>().unwrap(), status);
+        }
+        assert_eq!(ShipNavStatus::from("DOCKED"), ShipNavStatus::Docked);
+    }
+}
@@ -0,0 +1,72 @@
+use super::{Agent, ShipCargo, SupplyLevel, TradeSymbol};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Market {
+    pub symbol: String,
+    #[serde(default)]
+    pub exports: Vec<TradeGoodInfo>,
+    #[serde(default)]
+    pub imports: Vec<TradeGoodInfo>,
+    #[serde(default)]
+    pub exchange: Vec<TradeGoodInfo>,
+    /// Only reported while one of our ships is at the market.
+    #[serde(default)]
+    pub transactions: Vec<MarketTransaction>,
+    /// Only reported while one of our ships is at the market.
+    #[serde(rename = "tradeGoods", default)]
+    pub trade_goods: Vec<TradeGood>,
+}
+
+/// A good listed in a market's imports, exports or exchange, without prices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeGoodInfo {
+    pub symbol: TradeSymbol,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeGood {
+    pub symbol: TradeSymbol,
+    #[serde(rename = "type")]
+    pub good_type: Option<String>,
+    #[serde(rename = "tradeVolume")]
+    pub trade_volume: i32,
+    pub supply: SupplyLevel,
+    pub activity: Option<String>,
+    #[serde(rename = "purchasePrice")]
+    pub purchase_price: i32,
+    #[serde(rename = "sellPrice")]
+    pub sell_price: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketTransaction {
+    #[serde(rename = "waypointSymbol")]
+    pub waypoint_symbol: String,
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: String,
+    #[serde(rename = "tradeSymbol")]
+    pub trade_symbol: TradeSymbol,
+    #[serde(rename = "totalPrice")]
+    pub total_price: i32,
+    pub units: i32,
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SellCargoResponse {
+    pub agent: Agent,
+    pub cargo: ShipCargo,
+    pub transaction: MarketTransaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PurchaseCargoResponse {
+    pub agent: Agent,
+    pub cargo: ShipCargo,
+    pub transaction: MarketTransaction,
+}
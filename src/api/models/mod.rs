@@ -0,0 +1,63 @@
+//! Data types for the SpaceTraders schema, shared by every endpoint.
+//!
+//! Every type can be serialized again for storage. Only `Survey` is
+//! guaranteed to re-encode to the exact JSON it was decoded from, which is
+//! what lets a stored survey be sent back to `extract_resources_with_survey`;
+//! elsewhere fields the server omitted come back as `null` or an empty list.
+mod agent;
+mod construction;
+mod contract;
+mod enums;
+mod market;
+mod response;
+mod scan;
+mod ship;
+mod shipyard;
+mod status;
+mod survey;
+mod waypoint;
+
+// Public re-exports
+pub use self::{
+    agent::{Agent, AgentFaction, Faction, FactionTrait, RegisterResponse},
+    construction::{Construction, ConstructionMaterial, SupplyConstructionResponse},
+    contract::{
+        AcceptContractResponse, Contract, ContractDelivery, ContractPayment, ContractTerms,
+        DeliverContractResponse, FulfillContractResponse, NegotiateContractResponse,
+    },
+    enums::{
+        FactionSymbol, FlightMode, ShipNavStatus, ShipRole, SupplyLevel, SurveySize, TradeSymbol,
+        WaypointTraitSymbol, WaypointType,
+    },
+    market::{
+        Market, MarketTransaction, PurchaseCargoResponse, SellCargoResponse, TradeGood,
+        TradeGoodInfo,
+    },
+    response::{ApiResponse, Meta, Paginated},
+    scan::{
+        ScanShipsResponse, ScanSystemsResponse, ScanWaypointsResponse, ScannedComponent,
+        ScannedShip, ScannedSystem,
+    },
+    ship::{
+        CargoItem, CargoResponse, DockResponse, Extraction, ExtractionResponse, FlightModeResponse,
+        FuelConsumed, JettisonResponse, JumpResponse, NavigationResponse, OrbitResponse,
+        RefineAmount, RefineResponse, RefuelResponse, Requirements, Ship, ShipCargo,
+        ShipConditionEvent, ShipCooldown, ShipCrew, ShipEngine, ShipFrame, ShipFuel, ShipLocation,
+        ShipModule, ShipMount, ShipNav, ShipReactor, ShipRegistration, ShipRoute, Siphon,
+        SiphonResponse, TransferCargoResponse, WarpResponse, Yield,
+    },
+    shipyard::{
+        PurchaseShipResponse, RepairShipResponse, ScrapShipResponse, ShipModificationTransaction,
+        ShipModulesResponse, ShipMountsResponse, ShipServicePriceResponse, ShipServiceTransaction,
+        ShipTransaction, ShipType, Shipyard, ShipyardShip, ShipyardShipCrew,
+    },
+    status::{
+        Announcement, ChartsLeader, CreditsLeader, Leaderboards, ServerLink, ServerResets,
+        ServerStats, ServerStatus,
+    },
+    survey::{CreateSurveyResponse, Survey, SurveyDeposit},
+    waypoint::{
+        Chart, ChartResponse, JumpGate, System, SystemWaypoint, Waypoint, WaypointFaction,
+        WaypointModifier, WaypointOrbital, WaypointTrait,
+    },
+};
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiResponse<T> {
+    pub data: T,
+}
+
+/// One page of a list endpoint, with the `meta` block describing the rest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Paginated<T> {
+    pub data: Vec<T>,
+    pub meta: Meta,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Meta {
+    pub total: u32,
+    pub page: u32,
+    pub limit: u32,
+}
+
+impl Meta {
+    pub fn page_count(&self) -> u32 {
+        if self.limit == 0 {
+            return 0;
+        }
+        self.total.div_ceil(self.limit)
+    }
+
+    pub fn has_next_page(&self) -> bool {
+        self.page < self.page_count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(total: u32, page: u32, limit: u32) -> Meta {
+        Meta { total, page, limit }
+    }
+
+    #[test]
+    fn empty_list_has_no_pages() {
+        assert_eq!(meta(0, 1, 20).page_count(), 0);
+        assert!(!meta(0, 1, 20).has_next_page());
+    }
+
+    #[test]
+    fn exact_multiple_of_limit_has_no_extra_page() {
+        assert_eq!(meta(40, 1, 20).page_count(), 2);
+        assert!(meta(40, 1, 20).has_next_page());
+        assert!(!meta(40, 2, 20).has_next_page());
+    }
+
+    #[test]
+    fn partial_last_page_is_counted() {
+        assert_eq!(meta(45, 1, 20).page_count(), 3);
+        assert!(meta(45, 2, 20).has_next_page());
+        assert!(!meta(45, 3, 20).has_next_page());
+    }
+
+    #[test]
+    fn zero_limit_stops_paging() {
+        assert_eq!(meta(45, 1, 0).page_count(), 0);
+        assert!(!meta(45, 1, 0).has_next_page());
+    }
+}
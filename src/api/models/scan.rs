@@ -0,0 +1,51 @@
+use super::{ShipCooldown, ShipNav, ShipRegistration, Waypoint};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanSystemsResponse {
+    pub cooldown: ShipCooldown,
+    pub systems: Vec<ScannedSystem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScannedSystem {
+    pub symbol: String,
+    #[serde(rename = "sectorSymbol")]
+    pub sector_symbol: String,
+    pub r#type: String,
+    pub x: i32,
+    pub y: i32,
+    /// Distance from the scanning ship.
+    pub distance: i32,
+}
+
+/// Scanned waypoints carry the same fields as `get_waypoint_details`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanWaypointsResponse {
+    pub cooldown: ShipCooldown,
+    pub waypoints: Vec<Waypoint>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanShipsResponse {
+    pub cooldown: ShipCooldown,
+    pub ships: Vec<ScannedShip>,
+}
+
+/// Another agent's ship as seen by sensors; only component symbols are visible.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScannedShip {
+    pub symbol: String,
+    pub registration: ShipRegistration,
+    pub nav: ShipNav,
+    pub frame: Option<ScannedComponent>,
+    pub reactor: Option<ScannedComponent>,
+    pub engine: ScannedComponent,
+    #[serde(default)]
+    pub mounts: Vec<ScannedComponent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScannedComponent {
+    pub symbol: String,
+}
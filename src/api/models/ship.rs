@@ -0,0 +1,402 @@
+use super::{
+    Agent, FactionSymbol, FlightMode, MarketTransaction, ShipNavStatus, ShipRole, TradeSymbol,
+    WaypointType,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ship {
+    pub symbol: String,
+    pub nav: ShipNav,
+    pub crew: ShipCrew,
+    pub fuel: ShipFuel,
+    pub cooldown: ShipCooldown,
+    pub frame: ShipFrame,
+    pub reactor: ShipReactor,
+    pub engine: ShipEngine,
+    pub modules: Vec<ShipModule>,
+    pub mounts: Vec<ShipMount>,
+    pub registration: ShipRegistration,
+    pub cargo: ShipCargo,
+}
+
+impl Ship {
+    /// Whether the ship has a refinery module and can call `refine_ship`.
+    pub fn can_refine(&self) -> bool {
+        self.modules.iter().any(ShipModule::is_refinery)
+    }
+
+    /// Whether the ship has a gas siphon mount and can call `siphon_resources`.
+    pub fn can_siphon(&self) -> bool {
+        self.mounts.iter().any(ShipMount::is_gas_siphon)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipNav {
+    pub status: ShipNavStatus,
+    #[serde(rename = "waypointSymbol")]
+    pub waypoint_symbol: String,
+    #[serde(rename = "systemSymbol")]
+    pub system_symbol: String,
+    pub route: Option<ShipRoute>,
+    #[serde(rename = "flightMode")]
+    pub flight_mode: FlightMode,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipRoute {
+    pub destination: Option<ShipLocation>,
+    pub origin: Option<ShipLocation>,
+    #[serde(rename = "departureTime")]
+    pub departure_time: Option<String>,
+    #[serde(rename = "arrival")]
+    pub arrival_time: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipLocation {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub location_type: WaypointType,
+    #[serde(rename = "systemSymbol")]
+    pub system_symbol: String,
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipRegistration {
+    pub name: String,
+    #[serde(rename = "factionSymbol")]
+    pub faction_symbol: FactionSymbol,
+    pub role: ShipRole,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipCargo {
+    pub capacity: i32,
+    pub units: i32,
+    pub inventory: Vec<CargoItem>,
+}
+
+/// `GET /my/ships/{ship}/cargo` returns the same shape as `Ship::cargo`.
+pub type CargoResponse = ShipCargo;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CargoItem {
+    pub symbol: TradeSymbol,
+    pub name: String,
+    pub description: String,
+    pub units: i32,
+}
+
+/// The ship's hold after dropping cargo into space.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JettisonResponse {
+    pub cargo: CargoResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferCargoResponse {
+    /// The sending ship's hold after the transfer.
+    pub cargo: CargoResponse,
+    /// The receiving ship's hold, when the server reports it.
+    #[serde(rename = "targetCargo")]
+    pub target_cargo: Option<CargoResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipFuel {
+    pub current: i32,
+    pub capacity: i32,
+    pub consumed: Option<FuelConsumed>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuelConsumed {
+    pub amount: i32,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipCrew {
+    pub current: i32,
+    pub capacity: i32,
+    pub required: i32,
+    pub rotation: String,
+    pub morale: i32,
+    pub wages: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipCooldown {
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: String,
+    #[serde(rename = "totalSeconds")]
+    pub total_seconds: i32,
+    #[serde(rename = "remainingSeconds")]
+    pub remaining_seconds: i32,
+    pub expiration: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipFrame {
+    pub symbol: String,
+    pub name: String,
+    pub description: String,
+    #[serde(rename = "moduleSlots")]
+    pub module_slots: i32,
+    #[serde(rename = "mountingPoints")]
+    pub mounting_points: i32,
+    #[serde(rename = "fuelCapacity")]
+    pub fuel_capacity: i32,
+    pub condition: f32,
+    pub integrity: f32,
+    pub requirements: Requirements,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Requirements {
+    #[serde(default)]
+    pub power: i32,
+    #[serde(default)]
+    pub crew: i32,
+    #[serde(default)]
+    pub slots: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipReactor {
+    pub symbol: String,
+    pub name: String,
+    pub description: String,
+    pub condition: f32,
+    pub integrity: f32,
+    #[serde(rename = "powerOutput")]
+    pub power_output: i32,
+    pub requirements: Requirements,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipEngine {
+    pub symbol: String,
+    pub name: String,
+    pub description: String,
+    pub condition: f32,
+    pub integrity: f32,
+    pub speed: i32,
+    pub requirements: Requirements,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipModule {
+    pub symbol: TradeSymbol,
+    pub name: String,
+    pub description: String,
+    pub capacity: Option<i32>,
+    pub range: Option<i32>,
+    pub requirements: Requirements,
+}
+
+impl ShipModule {
+    /// Whether this module lets the ship call `refine_ship`.
+    pub fn is_refinery(&self) -> bool {
+        matches!(
+            self.symbol,
+            TradeSymbol::ModuleOreRefineryI
+                | TradeSymbol::ModuleFuelRefineryI
+                | TradeSymbol::ModuleMicroRefineryI
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipMount {
+    pub symbol: TradeSymbol,
+    pub name: String,
+    pub description: String,
+    pub strength: Option<i32>,
+    pub deposits: Option<Vec<TradeSymbol>>,
+    pub requirements: Requirements,
+}
+
+impl ShipMount {
+    /// Whether this mount lets the ship call `siphon_resources`.
+    pub fn is_gas_siphon(&self) -> bool {
+        matches!(
+            self.symbol,
+            TradeSymbol::MountGasSiphonI
+                | TradeSymbol::MountGasSiphonIi
+                | TradeSymbol::MountGasSiphonIii
+        )
+    }
+}
+
+/// Wear reported after navigating or extracting, e.g. a damaged reactor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipConditionEvent {
+    pub symbol: String,
+    pub component: String,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NavigationResponse {
+    pub nav: ShipNav,
+    pub fuel: ShipFuel,
+    #[serde(default)]
+    pub events: Vec<ShipConditionEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlightModeResponse {
+    pub nav: ShipNav,
+    pub fuel: ShipFuel,
+    #[serde(default)]
+    pub events: Vec<ShipConditionEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JumpResponse {
+    pub nav: ShipNav,
+    pub cooldown: ShipCooldown,
+    /// The antimatter charged for the jump.
+    pub transaction: MarketTransaction,
+    pub agent: Agent,
+}
+
+/// Warping burns fuel like a regular flight, so it reports the same fields.
+pub type WarpResponse = NavigationResponse;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrbitResponse {
+    pub nav: ShipNav,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockResponse {
+    pub nav: ShipNav,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefuelResponse {
+    pub agent: Agent,
+    pub fuel: ShipFuel,
+    pub transaction: MarketTransaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractionResponse {
+    pub extraction: Extraction,
+    pub cargo: ShipCargo,
+    pub cooldown: ShipCooldown,
+    #[serde(default)]
+    pub events: Vec<ShipConditionEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Extraction {
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: String,
+    #[serde(rename = "yield")]
+    pub yield_data: Yield,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiphonResponse {
+    pub siphon: Siphon,
+    pub cargo: ShipCargo,
+    pub cooldown: ShipCooldown,
+    #[serde(default)]
+    pub events: Vec<ShipConditionEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Siphon {
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: String,
+    #[serde(rename = "yield")]
+    pub yield_data: Yield,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Yield {
+    pub symbol: TradeSymbol,
+    pub units: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefineResponse {
+    pub cargo: ShipCargo,
+    pub cooldown: ShipCooldown,
+    pub produced: Vec<RefineAmount>,
+    pub consumed: Vec<RefineAmount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefineAmount {
+    #[serde(rename = "tradeSymbol")]
+    pub trade_symbol: TradeSymbol,
+    pub units: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(symbol: TradeSymbol) -> ShipModule {
+        ShipModule {
+            symbol,
+            name: String::new(),
+            description: String::new(),
+            capacity: None,
+            range: None,
+            requirements: Requirements::default(),
+        }
+    }
+
+    fn mount(symbol: TradeSymbol) -> ShipMount {
+        ShipMount {
+            symbol,
+            name: String::new(),
+            description: String::new(),
+            strength: None,
+            deposits: None,
+            requirements: Requirements::default(),
+        }
+    }
+
+    #[test]
+    fn refinery_modules_are_detected() {
+        for symbol in [
+            TradeSymbol::ModuleOreRefineryI,
+            TradeSymbol::ModuleFuelRefineryI,
+            TradeSymbol::ModuleMicroRefineryI,
+        ] {
+            assert!(module(symbol.clone()).is_refinery(), "{}", symbol);
+        }
+    }
+
+    #[test]
+    fn other_modules_are_not_refineries() {
+        assert!(!module(TradeSymbol::ModuleCargoHoldI).is_refinery());
+    }
+
+    #[test]
+    fn gas_siphon_mounts_are_detected() {
+        for symbol in [
+            TradeSymbol::MountGasSiphonI,
+            TradeSymbol::MountGasSiphonIi,
+            TradeSymbol::MountGasSiphonIii,
+        ] {
+            assert!(mount(symbol.clone()).is_gas_siphon(), "{}", symbol);
+        }
+    }
+
+    #[test]
+    fn other_mounts_are_not_gas_siphons() {
+        assert!(!mount(TradeSymbol::MountMiningLaserI).is_gas_siphon());
+    }
+}
@@ -0,0 +1,129 @@
+use super::{
+    Agent, Ship, ShipCargo, ShipEngine, ShipFrame, ShipModule, ShipMount, ShipReactor, TradeSymbol,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Shipyard {
+    pub symbol: String,
+    #[serde(rename = "shipTypes")]
+    pub ship_types: Vec<ShipType>,
+    /// Only reported while one of our ships is at the shipyard.
+    pub transactions: Option<Vec<ShipTransaction>>,
+    /// Only reported while one of our ships is at the shipyard.
+    pub ships: Option<Vec<ShipyardShip>>,
+    #[serde(rename = "modificationsFee", default)]
+    pub modifications_fee: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipType {
+    pub r#type: String,
+}
+
+/// A ship for sale at a shipyard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipyardShip {
+    pub r#type: String,
+    pub name: String,
+    pub description: String,
+    pub supply: Option<String>,
+    pub activity: Option<String>,
+    #[serde(rename = "purchasePrice")]
+    pub purchase_price: i32,
+    pub frame: ShipFrame,
+    pub reactor: ShipReactor,
+    pub engine: ShipEngine,
+    pub modules: Vec<ShipModule>,
+    pub mounts: Vec<ShipMount>,
+    pub crew: Option<ShipyardShipCrew>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipyardShipCrew {
+    pub required: i32,
+    pub capacity: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipTransaction {
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: Option<String>,
+    #[serde(rename = "shipType")]
+    pub ship_type: String,
+    #[serde(rename = "waypointSymbol")]
+    pub waypoint_symbol: String,
+    #[serde(rename = "agentSymbol")]
+    pub agent_symbol: String,
+    pub price: i32,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PurchaseShipResponse {
+    pub ship: Ship,
+    pub agent: Agent,
+    pub transaction: ShipTransaction,
+}
+
+/// The fee charged for installing or removing a mount or module.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipModificationTransaction {
+    #[serde(rename = "waypointSymbol")]
+    pub waypoint_symbol: String,
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: String,
+    /// The mount or module that was installed or removed.
+    #[serde(rename = "tradeSymbol")]
+    pub trade_symbol: TradeSymbol,
+    #[serde(rename = "totalPrice")]
+    pub total_price: i32,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipMountsResponse {
+    pub agent: Agent,
+    pub mounts: Vec<ShipMount>,
+    pub cargo: ShipCargo,
+    pub transaction: ShipModificationTransaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipModulesResponse {
+    pub agent: Agent,
+    pub modules: Vec<ShipModule>,
+    pub cargo: ShipCargo,
+    pub transaction: ShipModificationTransaction,
+}
+
+/// A repair or scrap quote, or the settled transaction once carried out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipServiceTransaction {
+    #[serde(rename = "waypointSymbol")]
+    pub waypoint_symbol: String,
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: String,
+    /// What the repair costs, or what scrapping pays out.
+    #[serde(rename = "totalPrice")]
+    pub total_price: i32,
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShipServicePriceResponse {
+    pub transaction: ShipServiceTransaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepairShipResponse {
+    pub agent: Agent,
+    pub ship: Ship,
+    pub transaction: ShipServiceTransaction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrapShipResponse {
+    pub agent: Agent,
+    pub transaction: ShipServiceTransaction,
+}
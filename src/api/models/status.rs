@@ -0,0 +1,72 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The root endpoint's view of the server; unlike other endpoints it is not wrapped in `data`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerStatus {
+    pub status: String,
+    pub version: String,
+    /// The day the universe was last reset; tokens issued before it are rejected.
+    #[serde(rename = "resetDate")]
+    pub reset_date: NaiveDate,
+    pub description: String,
+    pub stats: ServerStats,
+    pub leaderboards: Leaderboards,
+    #[serde(rename = "serverResets")]
+    pub server_resets: ServerResets,
+    #[serde(default)]
+    pub announcements: Vec<Announcement>,
+    #[serde(default)]
+    pub links: Vec<ServerLink>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerStats {
+    pub accounts: Option<i32>,
+    pub agents: i32,
+    pub ships: i32,
+    pub systems: i32,
+    pub waypoints: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Leaderboards {
+    #[serde(rename = "mostCredits")]
+    pub most_credits: Vec<CreditsLeader>,
+    #[serde(rename = "mostSubmittedCharts")]
+    pub most_submitted_charts: Vec<ChartsLeader>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditsLeader {
+    #[serde(rename = "agentSymbol")]
+    pub agent_symbol: String,
+    pub credits: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartsLeader {
+    #[serde(rename = "agentSymbol")]
+    pub agent_symbol: String,
+    #[serde(rename = "chartCount")]
+    pub chart_count: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerResets {
+    pub next: DateTime<Utc>,
+    /// How often resets happen, e.g. `weekly`.
+    pub frequency: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Announcement {
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerLink {
+    pub name: String,
+    pub url: String,
+}
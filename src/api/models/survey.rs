@@ -0,0 +1,70 @@
+use super::{ShipCooldown, SurveySize, TradeSymbol};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A scan of a waypoint's deposits, valid for targeted extraction until it expires.
+///
+/// The server checks the signature against the whole survey, so pass it back
+/// to `extract_resources_with_survey` exactly as it was received. For that
+/// reason `expiration` keeps the server's original timestamp string; use
+/// `expires_at` for the parsed value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Survey {
+    pub signature: String,
+    /// The waypoint the survey was taken at.
+    pub symbol: String,
+    pub deposits: Vec<SurveyDeposit>,
+    pub expiration: String,
+    pub size: SurveySize,
+}
+
+impl Survey {
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        DateTime::parse_from_rfc3339(&self.expiration)
+            .ok()
+            .map(|expiration| expiration.with_timezone(&Utc))
+    }
+
+    /// An unparseable expiration is left for the server to judge.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at()
+            .is_some_and(|expiration| now >= expiration)
+    }
+
+    /// How many of the survey's deposits are goods in `wanted`.
+    ///
+    /// Deposits are listed once per expected yield, so repeats count again.
+    pub fn matching_deposits(&self, wanted: &[TradeSymbol]) -> usize {
+        self.deposits
+            .iter()
+            .filter(|deposit| wanted.contains(&deposit.symbol))
+            .count()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurveyDeposit {
+    pub symbol: TradeSymbol,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSurveyResponse {
+    pub cooldown: ShipCooldown,
+    pub surveys: Vec<Survey>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expiration_round_trips_unchanged() {
+        let json = r#"{"signature":"X1-A1-1","symbol":"X1-A1","deposits":[{"symbol":"IRON_ORE"}],"expiration":"2023-06-10T15:39:26.000Z","size":"SMALL"}"#;
+        let survey: Survey = serde_json::from_str(json).unwrap();
+
+        assert_eq!(serde_json::to_string(&survey).unwrap(), json);
+        let expires_at = survey.expires_at().unwrap();
+        assert!(!survey.is_expired(expires_at - chrono::Duration::seconds(1)));
+        assert!(survey.is_expired(expires_at));
+    }
+}
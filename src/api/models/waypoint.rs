@@ -0,0 +1,106 @@
+use super::{FactionSymbol, WaypointTraitSymbol, WaypointType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Waypoint {
+    pub symbol: String,
+    pub r#type: WaypointType,
+    #[serde(rename = "systemSymbol")]
+    pub system_symbol: String,
+    pub x: i32,
+    pub y: i32,
+    #[serde(default)]
+    pub orbitals: Vec<WaypointOrbital>,
+    pub orbits: Option<String>,
+    pub faction: Option<WaypointFaction>,
+    #[serde(default)]
+    pub traits: Vec<WaypointTrait>,
+    #[serde(default)]
+    pub modifiers: Vec<WaypointModifier>,
+    #[serde(rename = "isUnderConstruction", default)]
+    pub is_under_construction: bool,
+    /// Who charted the waypoint; `None` until someone does.
+    pub chart: Option<Chart>,
+}
+
+impl Waypoint {
+    /// Uncharted waypoints hide their real traits until a ship charts them.
+    pub fn is_uncharted(&self) -> bool {
+        self.traits
+            .iter()
+            .any(|trait_| trait_.symbol == WaypointTraitSymbol::Uncharted)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Chart {
+    #[serde(rename = "waypointSymbol")]
+    pub waypoint_symbol: Option<String>,
+    #[serde(rename = "submittedBy")]
+    pub submitted_by: String,
+    #[serde(rename = "submittedOn")]
+    pub submitted_on: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartResponse {
+    pub chart: Chart,
+    pub waypoint: Waypoint,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaypointTrait {
+    pub symbol: WaypointTraitSymbol,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaypointModifier {
+    pub symbol: String,
+    pub name: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaypointOrbital {
+    pub symbol: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaypointFaction {
+    pub symbol: FactionSymbol,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct System {
+    pub symbol: String,
+    #[serde(rename = "sectorSymbol")]
+    pub sector_symbol: String,
+    pub r#type: String,
+    pub x: i32,
+    pub y: i32,
+    pub waypoints: Vec<SystemWaypoint>,
+    #[serde(default)]
+    pub factions: Vec<WaypointFaction>,
+}
+
+/// The short waypoint listing embedded in a `System`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemWaypoint {
+    pub symbol: String,
+    pub r#type: WaypointType,
+    pub x: i32,
+    pub y: i32,
+    #[serde(default)]
+    pub orbitals: Vec<WaypointOrbital>,
+    pub orbits: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JumpGate {
+    pub symbol: String,
+    /// Waypoint symbols of the gates this one connects to, in other systems.
+    pub connections: Vec<String>,
+}
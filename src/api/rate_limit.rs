@@ -0,0 +1,196 @@
+use log::warn;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Client-side mirror of the SpaceTraders request limits.
+///
+/// The server refills a small static bucket every second and keeps a
+/// separate burst pool that refills over a longer window. Requests draw from
+/// the static bucket first and fall back to the burst pool.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    pub requests_per_second: f64,
+    pub burst: u32,
+    pub burst_window: Duration,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 2.0,
+            burst: 30,
+            burst_window: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RateLimit {
+    /// Replaces settings the limiter cannot pace with (a non-positive or
+    /// non-finite rate, or a zero burst window) by their defaults.
+    fn sanitized(self) -> Self {
+        let default = Self::default();
+        let mut limit = self;
+
+        if !(limit.requests_per_second.is_finite() && limit.requests_per_second > 0.0) {
+            warn!(
+                "Invalid rate limit of {} requests per second, using {}",
+                limit.requests_per_second, default.requests_per_second
+            );
+            limit.requests_per_second = default.requests_per_second;
+        }
+        if limit.burst_window.is_zero() {
+            warn!(
+                "Invalid zero burst window, using {:?}",
+                default.burst_window
+            );
+            limit.burst_window = default.burst_window;
+        }
+
+        limit
+    }
+
+    fn static_capacity(&self) -> f64 {
+        self.requests_per_second.max(1.0)
+    }
+}
+
+#[derive(Debug)]
+struct Buckets {
+    static_tokens: f64,
+    burst_tokens: f64,
+    last_refill: Instant,
+}
+
+/// Token-bucket limiter shared by every clone of a client.
+///
+/// Waiters queue on a fair mutex, so concurrent tasks are served in the
+/// order they asked for a permit.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    buckets: Mutex<Buckets>,
+}
+
+impl RateLimiter {
+    /// Invalid settings fall back to their `RateLimit::default()` values.
+    pub fn new(limit: RateLimit) -> Self {
+        let limit = limit.sanitized();
+        Self {
+            limit,
+            buckets: Mutex::new(Buckets {
+                static_tokens: limit.static_capacity(),
+                burst_tokens: limit.burst as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Waits until a request may be sent and consumes one token.
+    pub async fn acquire(&self) {
+        let mut buckets = self.buckets.lock().await;
+        loop {
+            self.refill(&mut buckets);
+
+            if buckets.static_tokens >= 1.0 {
+                buckets.static_tokens -= 1.0;
+                return;
+            }
+            if buckets.burst_tokens >= 1.0 {
+                buckets.burst_tokens -= 1.0;
+                return;
+            }
+
+            // Hold the lock while sleeping so later callers keep their place in line.
+            let missing = 1.0 - buckets.static_tokens;
+            tokio::time::sleep(Duration::from_secs_f64(
+                missing / self.limit.requests_per_second,
+            ))
+            .await;
+        }
+    }
+
+    fn refill(&self, buckets: &mut Buckets) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(buckets.last_refill).as_secs_f64();
+        buckets.last_refill = now;
+
+        buckets.static_tokens = (buckets.static_tokens + elapsed * self.limit.requests_per_second)
+            .min(self.limit.static_capacity());
+
+        let burst = self.limit.burst as f64;
+        let burst_rate = burst / self.limit.burst_window.as_secs_f64();
+        buckets.burst_tokens = (buckets.burst_tokens + elapsed * burst_rate).min(burst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(requests_per_second: f64, burst: u32) -> RateLimiter {
+        RateLimiter::new(RateLimit {
+            requests_per_second,
+            burst,
+            burst_window: Duration::from_secs(60),
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn burst_pool_is_drained_before_waiting() {
+        let limiter = limiter(2.0, 3);
+        let start = Instant::now();
+
+        // Two static tokens plus three burst tokens are available immediately.
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_millis(500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn steady_state_follows_requests_per_second() {
+        let limiter = limiter(2.0, 0);
+        limiter.acquire().await;
+        limiter.acquire().await;
+
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn invalid_settings_fall_back_to_defaults() {
+        let default = RateLimit::default();
+
+        for requests_per_second in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let limiter = limiter(requests_per_second, 0);
+            assert_eq!(
+                limiter.limit().requests_per_second,
+                default.requests_per_second
+            );
+            for _ in 0..3 {
+                limiter.acquire().await;
+            }
+        }
+
+        let limiter = RateLimiter::new(RateLimit {
+            requests_per_second: 2.0,
+            burst: 5,
+            burst_window: Duration::ZERO,
+        });
+        assert_eq!(limiter.limit().burst_window, default.burst_window);
+        for _ in 0..10 {
+            limiter.acquire().await;
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::api::models::{ApiResponse, FactionSymbol, RegisterResponse};
+use crate::api::SpaceTradersClient;
+use anyhow::{Context, Result};
+use log::info;
+use serde::Serialize;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// Where `save_token` writes the agent token by default; `from_env` reads it back.
+pub const DEFAULT_ENV_FILE: &str = ".env";
+
+#[derive(Debug, Serialize)]
+pub struct RegisterRequest {
+    pub symbol: String,
+    pub faction: FactionSymbol,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+impl SpaceTradersClient {
+    /// Creates a new agent. The client's own token is sent as the account
+    /// token if one is set; the returned agent token is in the response.
+    pub async fn register(
+        &self,
+        symbol: &str,
+        faction: &FactionSymbol,
+        email: Option<&str>,
+    ) -> Result<ApiResponse<RegisterResponse>> {
+        info!("Registering agent {} with faction {}", symbol, faction);
+        let request = RegisterRequest {
+            symbol: symbol.to_string(),
+            faction: faction.clone(),
+            email: email.map(str::to_string),
+        };
+
+        self.post("/register", &request).await
+    }
+}
+
+/// Writes `BEARER_TOKEN=<token>` to an env file, replacing any previous
+/// token in place and keeping every other line as it was.
+pub fn save_token(path: impl AsRef<Path>, token: &str) -> Result<()> {
+    let path = path.as_ref();
+    let existing = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+    };
+
+    let mut replaced = false;
+    let mut lines = Vec::new();
+    for line in existing.lines() {
+        let trimmed = line.trim_start();
+        let export = trimmed.starts_with("export ");
+        let assignment = trimmed.strip_prefix("export ").unwrap_or(trimmed);
+
+        if !assignment.starts_with("BEARER_TOKEN=") {
+            lines.push(line.to_string());
+        } else if !replaced {
+            // Keep an `export` prefix so shells sourcing the file still see the token.
+            let prefix = if export { "export " } else { "" };
+            lines.push(format!("{}BEARER_TOKEN={}", prefix, token));
+            replaced = true;
+        }
+    }
+    if !replaced {
+        lines.push(format!("BEARER_TOKEN={}", token));
+    }
+
+    fs::write(path, lines.join("\n") + "\n")
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn save(contents: Option<&str>) -> String {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join(".env");
+        if let Some(contents) = contents {
+            fs::write(&path, contents).unwrap();
+        }
+
+        save_token(&path, "new-token").unwrap();
+        fs::read_to_string(&path).unwrap()
+    }
+
+    #[test]
+    fn creates_a_missing_file() {
+        assert_eq!(save(None), "BEARER_TOKEN=new-token\n");
+    }
+
+    #[test]
+    fn keeps_other_keys_in_order() {
+        assert_eq!(
+            save(Some("ACCOUNT_TOKEN=account\n# comment\nRUST_LOG=debug\n")),
+            "ACCOUNT_TOKEN=account\n# comment\nRUST_LOG=debug\nBEARER_TOKEN=new-token\n"
+        );
+    }
+
+    #[test]
+    fn replaces_a_previous_token_in_place() {
+        assert_eq!(
+            save(Some(
+                "A=1\nBEARER_TOKEN=old-token\nB=2\nBEARER_TOKEN=older-token\n"
+            )),
+            "A=1\nBEARER_TOKEN=new-token\nB=2\n"
+        );
+    }
+
+    #[test]
+    fn replaces_an_exported_token() {
+        assert_eq!(
+            save(Some("export BEARER_TOKEN=old-token\nA=1\n")),
+            "export BEARER_TOKEN=new-token\nA=1\n"
+        );
+    }
+
+    #[test]
+    fn ends_with_a_single_trailing_newline() {
+        assert_eq!(save(Some("A=1\n")), "A=1\nBEARER_TOKEN=new-token\n");
+        assert_eq!(save(Some("A=1")), "A=1\nBEARER_TOKEN=new-token\n");
+    }
+}
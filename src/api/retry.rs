@@ -0,0 +1,303 @@
+use chrono::{DateTime, Utc};
+use reqwest::{Method, Response, StatusCode};
+use std::time::Duration;
+
+/// How the client retries transient failures.
+///
+/// 429 responses wait for the server's `Retry-After` / `X-RateLimit-Reset`
+/// hint, capped at `max_delay`, and are retried for every method, since a
+/// rate-limited request is rejected before it is processed. 5xx responses and transport errors use
+/// exponential backoff with jitter, but are only retried for idempotent
+/// methods; a POST is retried only when the connection was never made.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retries; every failure is returned immediately.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before retrying `response`, or `None` if it should be returned as is.
+    pub(crate) fn delay_for_response(
+        &self,
+        response: &Response,
+        method: &Method,
+        attempt: u32,
+    ) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+
+        let status = response.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            return Some(
+                retry_after(response)
+                    .map(|delay| delay.min(self.max_delay))
+                    .unwrap_or_else(|| self.backoff(attempt)),
+            );
+        }
+        if status.is_server_error() && is_idempotent(method) {
+            return Some(self.backoff(attempt));
+        }
+
+        None
+    }
+
+    /// Delay before retrying after a transport error, or `None` to give up.
+    pub(crate) fn delay_for_error(
+        &self,
+        error: &reqwest::Error,
+        method: &Method,
+        attempt: u32,
+    ) -> Option<Duration> {
+        if attempt >= self.max_retries || error.is_builder() {
+            return None;
+        }
+
+        // A failed connect means nothing reached the server, so any method is safe to resend.
+        let retryable = error.is_connect()
+            || (is_idempotent(method) && (error.is_timeout() || error.is_request()));
+        retryable.then(|| self.backoff(attempt))
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        // "Equal jitter": keep half the delay and randomize the rest.
+        let half = exponential / 2;
+        half + half.mul_f64(fastrand::f64())
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+/// Reads how long the server asked us to wait from the rate limit headers.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let headers = response.headers();
+
+    if let Some(seconds) = headers
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+    {
+        return Some(Duration::from_secs_f64(seconds));
+    }
+
+    let reset = headers
+        .get("x-ratelimit-reset")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| DateTime::parse_from_rfc3339(value.trim()).ok())?;
+    (reset.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    fn response(status: u16, headers: &[(&str, &str)]) -> Response {
+        let mut builder = http::Response::builder().status(status);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        Response::from(builder.body("").unwrap())
+    }
+
+    fn assert_backoff(policy: &RetryPolicy, delay: Duration, attempt: u32) {
+        let full = policy
+            .base_delay
+            .saturating_mul(2u32.pow(attempt))
+            .min(policy.max_delay);
+        assert!(
+            delay >= full / 2 && delay <= full,
+            "{:?} for attempt {}",
+            delay,
+            attempt
+        );
+    }
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let policy = RetryPolicy::default();
+        for attempt in 0..10 {
+            assert_backoff(&policy, policy.backoff(attempt), attempt);
+        }
+        assert!(policy.backoff(u32::MAX) <= policy.max_delay);
+    }
+
+    #[test]
+    fn rate_limited_responses_are_retried_for_every_method() {
+        let policy = RetryPolicy::default();
+        let limited = response(429, &[("retry-after", "2")]);
+
+        assert_eq!(
+            policy.delay_for_response(&limited, &Method::POST, 0),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.delay_for_response(&limited, &Method::GET, 0),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn server_retry_after_is_capped_at_max_delay() {
+        let policy = RetryPolicy::default();
+        let limited = response(429, &[("retry-after", "3600")]);
+
+        assert_eq!(
+            policy.delay_for_response(&limited, &Method::POST, 0),
+            Some(policy.max_delay)
+        );
+    }
+
+    #[test]
+    fn rate_limit_reset_header_is_used_without_retry_after() {
+        let reset = (Utc::now() + chrono::Duration::seconds(10)).to_rfc3339();
+        let limited = response(429, &[("x-ratelimit-reset", &reset)]);
+
+        let delay = RetryPolicy::default()
+            .delay_for_response(&limited, &Method::POST, 0)
+            .unwrap();
+        assert!(delay > Duration::from_secs(8) && delay <= Duration::from_secs(10));
+    }
+
+    #[test]
+    fn rate_limited_without_hint_falls_back_to_backoff() {
+        let policy = RetryPolicy::default();
+        let delay = policy
+            .delay_for_response(&response(429, &[]), &Method::POST, 1)
+            .unwrap();
+        assert_backoff(&policy, delay, 1);
+    }
+
+    #[test]
+    fn server_errors_are_retried_only_for_idempotent_methods() {
+        let policy = RetryPolicy::default();
+        let unavailable = response(503, &[]);
+
+        assert_eq!(
+            policy.delay_for_response(&unavailable, &Method::POST, 0),
+            None
+        );
+        assert_eq!(
+            policy.delay_for_response(&unavailable, &Method::PATCH, 0),
+            None
+        );
+        let delay = policy
+            .delay_for_response(&unavailable, &Method::GET, 0)
+            .unwrap();
+        assert_backoff(&policy, delay, 0);
+    }
+
+    #[test]
+    fn other_responses_and_exhausted_attempts_are_not_retried() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(
+            policy.delay_for_response(&response(400, &[]), &Method::GET, 0),
+            None
+        );
+        assert_eq!(
+            policy.delay_for_response(&response(200, &[]), &Method::GET, 0),
+            None
+        );
+        assert_eq!(
+            policy.delay_for_response(&response(503, &[]), &Method::GET, policy.max_retries),
+            None
+        );
+        assert_eq!(
+            RetryPolicy::none().delay_for_response(&response(429, &[]), &Method::GET, 0),
+            None
+        );
+    }
+
+    /// A local port with nothing listening on it.
+    fn closed_port() -> u16 {
+        TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port()
+    }
+
+    #[tokio::test]
+    async fn connect_errors_are_retried_for_every_method() {
+        let policy = RetryPolicy::default();
+        let error = reqwest::Client::new()
+            .post(format!("http://127.0.0.1:{}", closed_port()))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(error.is_connect());
+
+        assert!(policy.delay_for_error(&error, &Method::POST, 0).is_some());
+        assert!(policy.delay_for_error(&error, &Method::GET, 0).is_some());
+        assert_eq!(
+            policy.delay_for_error(&error, &Method::POST, policy.max_retries),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn timeouts_are_retried_only_for_idempotent_methods() {
+        // The listener accepts connections into its backlog but never answers.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let error = reqwest::Client::new()
+            .post(format!("http://{}", listener.local_addr().unwrap()))
+            .timeout(Duration::from_millis(50))
+            .send()
+            .await
+            .unwrap_err();
+        assert!(error.is_timeout());
+
+        assert_eq!(
+            RetryPolicy::default().delay_for_error(&error, &Method::POST, 0),
+            None
+        );
+        assert!(RetryPolicy::default()
+            .delay_for_error(&error, &Method::GET, 0)
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn builder_errors_are_never_retried() {
+        let error = reqwest::Client::new()
+            .get("not a url")
+            .send()
+            .await
+            .unwrap_err();
+        assert!(error.is_builder());
+
+        assert_eq!(
+            RetryPolicy::default().delay_for_error(&error, &Method::GET, 0),
+            None
+        );
+    }
+}
@@ -1,97 +1,17 @@
-use serde::{Deserialize, Serialize};
-use crate::api::ApiResponse;
-use log::{debug, error, info};
-
-#[derive(Debug, Deserialize)]
-pub struct Ship {
-    pub symbol: String,
-    pub nav: ShipNav,
-    pub crew: ShipCrew,
-    pub fuel: ShipFuel,
-    pub cooldown: ShipCooldown,
-    pub frame: ShipFrame,
-    pub reactor: ShipReactor,
-    pub engine: ShipEngine,
-    pub modules: Vec<ShipModule>,
-    pub mounts: Vec<ShipMount>,
-    pub registration: ShipRegistration,
-    pub cargo: ShipCargo,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipNav {
-    pub status: String,
-    #[serde(rename = "waypointSymbol")]
-    pub waypoint_symbol: String,
-    #[serde(rename = "systemSymbol")]
-    pub system_symbol: String,
-    pub route: Option<ShipRoute>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipRoute {
-    pub destination: Option<ShipLocation>,
-    pub departure: Option<ShipLocation>,
-    #[serde(rename = "departureTime")]
-    pub departure_time: Option<String>,
-    #[serde(rename = "arrivalTime")]
-    pub arrival_time: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipLocation {
-    pub symbol: String,
-    #[serde(rename = "type")]
-    pub location_type: String,
-    #[serde(rename = "systemSymbol")]
-    pub system_symbol: String,
-    pub x: i32,
-    pub y: i32,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipRegistration {
-    pub name: String,
-    #[serde(rename = "factionSymbol")]
-    pub faction_symbol: String,
-    pub role: String,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipCargo {
-    pub capacity: i32,
-    pub units: i32,
-    pub inventory: Vec<CargoItem>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct CargoItem {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-    pub units: i32,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipFuel {
-    pub current: i32,
-    pub capacity: i32,
-    pub consumed: FuelConsumed,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct FuelConsumed {
-    pub amount: i32,
-    pub timestamp: String,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct Waypoint {
-    pub symbol: String,
-    pub r#type: String,
-    pub x: i32,
-    pub y: i32,
-}
+use crate::api::models::{
+    ApiResponse, CargoResponse, ChartResponse, CreateSurveyResponse, DockResponse,
+    ExtractionResponse, FlightMode, FlightModeResponse, JettisonResponse, JumpResponse, Market,
+    NavigationResponse, OrbitResponse, Paginated, PurchaseCargoResponse, PurchaseShipResponse,
+    RefineResponse, RefuelResponse, ScanShipsResponse, ScanSystemsResponse, ScanWaypointsResponse,
+    SellCargoResponse, Ship, ShipCooldown, SiphonResponse, Survey, TradeSymbol,
+    TransferCargoResponse, WarpResponse, Waypoint, WaypointType,
+};
+use crate::api::{SpaceTradersClient, SpaceTradersError, WaypointFilters};
+use futures_util::Stream;
+use log::debug;
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 #[derive(Serialize)]
 pub struct NavigateRequest {
@@ -99,112 +19,10 @@ pub struct NavigateRequest {
     pub waypoint_symbol: String,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct PurchaseShipResponse {
-    pub ship: Ship,
-    pub agent: super::api::Agent,
-    pub transaction: ShipTransaction,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipTransaction {
-    #[serde(rename = "shipSymbol")]
-    pub ship_symbol: String,
-    #[serde(rename = "shipType")]
-    pub ship_type: String,
-    #[serde(rename = "waypointSymbol")]
-    pub waypoint_symbol: String,
-    #[serde(rename = "agentSymbol")]
-    pub agent_symbol: String,
-    pub price: i32,
-    pub timestamp: String,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipCrew {
-    pub current: i32,
-    pub capacity: i32,
-    pub required: i32,
-    pub rotation: String,
-    pub morale: i32,
-    pub wages: i32,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipCooldown {
-    #[serde(rename = "shipSymbol")]
-    pub ship_symbol: String,
-    #[serde(rename = "totalSeconds")]
-    pub total_seconds: i32,
-    #[serde(rename = "remainingSeconds")]
-    pub remaining_seconds: i32,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipFrame {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-    #[serde(rename = "moduleSlots")]
-    pub module_slots: i32,
-    #[serde(rename = "mountingPoints")]
-    pub mounting_points: i32,
-    #[serde(rename = "fuelCapacity")]
-    pub fuel_capacity: i32,
-    pub condition: f32,
-    pub integrity: f32,
-    pub requirements: Requirements,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct Requirements {
-    #[serde(default)]
-    pub power: i32,
-    #[serde(default)]
-    pub crew: i32,
-    #[serde(default)]
-    pub slots: i32,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipReactor {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-    pub condition: f32,
-    pub integrity: f32,
-    #[serde(rename = "powerOutput")]
-    pub power_output: i32,
-    pub requirements: Requirements,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipEngine {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-    pub condition: f32,
-    pub integrity: f32,
-    pub speed: i32,
-    pub requirements: Requirements,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipModule {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-    pub capacity: Option<i32>,
-    pub requirements: Requirements,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipMount {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-    pub strength: Option<i32>,
-    pub requirements: Requirements,
+#[derive(Debug, Serialize)]
+pub struct FlightModeRequest {
+    #[serde(rename = "flightMode")]
+    pub flight_mode: FlightMode,
 }
 
 #[derive(Debug, Serialize)]
@@ -215,478 +33,407 @@ pub struct PurchaseShipRequest {
     pub waypoint_symbol: String,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ErrorResponse {
-    pub error: ErrorDetail,
+#[derive(Debug, Serialize)]
+pub struct SellCargoRequest {
+    pub symbol: TradeSymbol,
+    pub units: i32,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ErrorDetail {
-    pub message: String,
-    pub code: i32,
-    pub data: Option<serde_json::Value>,
+#[derive(Debug, Serialize)]
+pub struct PurchaseCargoRequest {
+    pub symbol: TradeSymbol,
+    pub units: i32,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct NavigationResponse {
-    pub nav: ShipNav,
-    pub fuel: ShipFuel,
-    #[serde(default)]
-    pub events: Vec<String>,
+#[derive(Debug, Serialize)]
+pub struct RefineRequest {
+    pub produce: TradeSymbol,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct ExtractionResponse {
-    pub extraction: Extraction,
-    pub cargo: ShipCargo,
-    pub cooldown: ShipCooldown,
+#[derive(Debug, Serialize)]
+pub struct JettisonRequest {
+    pub symbol: TradeSymbol,
+    pub units: i32,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct Extraction {
+#[derive(Debug, Serialize)]
+pub struct TransferCargoRequest {
+    #[serde(rename = "tradeSymbol")]
+    pub trade_symbol: TradeSymbol,
+    pub units: i32,
     #[serde(rename = "shipSymbol")]
     pub ship_symbol: String,
-    #[serde(rename = "yield")]
-    pub yield_data: Yield,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct Yield {
-    pub symbol: String,
-    pub units: i32,
-}
+impl SpaceTradersClient {
+    /// Fetches every ship in the fleet, following pagination.
+    pub async fn list_ships(&self) -> anyhow::Result<Vec<Ship>> {
+        self.get_all("/my/ships").await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct CooldownError {
-    pub error: CooldownErrorDetail,
-}
+    pub async fn list_ships_page(&self, page: u32, limit: u32) -> anyhow::Result<Paginated<Ship>> {
+        self.get_page("/my/ships", page, limit).await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct CooldownErrorDetail {
-    pub message: String,
-    pub code: i32,
-    pub data: CooldownData,
-}
+    pub fn ships_stream(&self) -> impl Stream<Item = anyhow::Result<Ship>> + '_ {
+        self.paginate("/my/ships".to_string())
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct CooldownData {
-    #[serde(rename = "cooldownRemaining")]
-    pub cooldown_remaining: i32,
-    #[serde(rename = "totalCooldown")]
-    pub total_cooldown: i32,
-    pub expiration: String,
-}
+    pub async fn find_asteroids(&self, system_symbol: &str) -> anyhow::Result<Vec<Waypoint>> {
+        let filters = WaypointFilters::new().with_type(WaypointType::EngineeredAsteroid);
+        self.list_waypoints(system_symbol, &filters).await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct Market {
-    pub symbol: String,
-    #[serde(rename = "tradeGoods")]
-    pub trade_goods: Vec<TradeGood>,
-}
+    /// Gas giants are where `siphon_resources` yields hydrocarbons and liquid gases.
+    pub async fn find_gas_giants(&self, system_symbol: &str) -> anyhow::Result<Vec<Waypoint>> {
+        let filters = WaypointFilters::new().with_type(WaypointType::GasGiant);
+        self.list_waypoints(system_symbol, &filters).await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct TradeGood {
-    pub symbol: String,
-    #[serde(rename = "tradeVolume")]
-    pub trade_volume: i32,
-    pub supply: String,
-    #[serde(rename = "purchasePrice")]
-    pub purchase_price: i32,
-    #[serde(rename = "sellPrice")]
-    pub sell_price: i32,
-}
+    pub async fn orbit_ship(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<OrbitResponse>> {
+        debug!("Attempting to orbit ship: {}", ship_symbol);
+        self.post(
+            &format!("/my/ships/{}/orbit", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct CargoResponse {
-    pub capacity: i32,
-    pub units: i32,
-    pub inventory: Vec<CargoItem>,
-}
+    pub async fn navigate_ship(
+        &self,
+        ship_symbol: &str,
+        waypoint_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<NavigationResponse>> {
+        let request = NavigateRequest {
+            waypoint_symbol: waypoint_symbol.to_string(),
+        };
+
+        self.post(&format!("/my/ships/{}/navigate", ship_symbol), &request)
+            .await
+    }
 
-#[derive(Debug, Serialize)]
-pub struct SellCargoRequest {
-    pub symbol: String,
-    pub units: i32,
-}
+    /// Jumps to a connected gate in another system; the ship must be in orbit at a jump gate.
+    pub async fn jump_ship(
+        &self,
+        ship_symbol: &str,
+        waypoint_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<JumpResponse>> {
+        let request = NavigateRequest {
+            waypoint_symbol: waypoint_symbol.to_string(),
+        };
+
+        self.post(&format!("/my/ships/{}/jump", ship_symbol), &request)
+            .await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct SellCargoResponse {
-    pub agent: super::api::Agent,
-    pub cargo: CargoResponse,
-    pub transaction: MarketTransaction,
-}
+    /// Flies to a waypoint in another system; requires a warp drive.
+    pub async fn warp_ship(
+        &self,
+        ship_symbol: &str,
+        waypoint_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<WarpResponse>> {
+        let request = NavigateRequest {
+            waypoint_symbol: waypoint_symbol.to_string(),
+        };
+
+        self.post(&format!("/my/ships/{}/warp", ship_symbol), &request)
+            .await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct MarketTransaction {
-    #[serde(rename = "waypointSymbol")]
-    pub waypoint_symbol: String,
-    #[serde(rename = "shipSymbol")]
-    pub ship_symbol: String,
-    #[serde(rename = "tradeSymbol")]
-    pub trade_symbol: String,
-    #[serde(rename = "totalPrice")]
-    pub total_price: i32,
-    pub units: i32,
-    #[serde(rename = "type")]
-    pub transaction_type: String,
-    pub timestamp: String,
-}
+    /// Sets the speed/fuel trade-off used by later `navigate_ship` calls.
+    pub async fn set_flight_mode(
+        &self,
+        ship_symbol: &str,
+        flight_mode: FlightMode,
+    ) -> anyhow::Result<ApiResponse<FlightModeResponse>> {
+        debug!(
+            "Setting flight mode of ship {} to {}",
+            ship_symbol, flight_mode
+        );
+        let request = FlightModeRequest { flight_mode };
+
+        self.patch(&format!("/my/ships/{}/nav", ship_symbol), &request)
+            .await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct OrbitResponse {
-    pub nav: ShipNav,
-}
+    pub async fn dock_ship(&self, ship_symbol: &str) -> anyhow::Result<ApiResponse<DockResponse>> {
+        debug!("Attempting to dock ship: {}", ship_symbol);
+        self.post(
+            &format!("/my/ships/{}/dock", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
+    }
 
-#[derive(Debug, Deserialize)]
-pub struct DockResponse {
-    pub nav: ShipNav,
-}
+    pub async fn refuel_ship(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<RefuelResponse>> {
+        self.post(
+            &format!("/my/ships/{}/refuel", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
+    }
 
-pub async fn list_ships() -> anyhow::Result<ApiResponse<Vec<Ship>>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .get("https://api.spacetraders.io/v2/my/ships")
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?
-        .json()
-        .await?;
-
-    Ok(response)
-}
+    /// Returns the ship's active cooldown, or `None` once it has expired.
+    pub async fn check_extraction_cooldown(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<Option<ShipCooldown>> {
+        let response = self
+            .execute(self.request(Method::GET, &format!("/my/ships/{}/cooldown", ship_symbol)))
+            .await?;
+
+        let status = response.status();
+        let response_text = response.text().await?;
+
+        // The server answers 204 No Content when the ship has no cooldown.
+        if status == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(SpaceTradersError::from_response(status.as_u16(), &response_text).into());
+        }
 
-pub async fn find_asteroids(system_symbol: &str) -> anyhow::Result<ApiResponse<Vec<Waypoint>>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let url = format!(
-        "https://api.spacetraders.io/v2/systems/{}/waypoints?type=ENGINEERED_ASTEROID",
-        system_symbol
-    );
-    
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?
-        .json()
-        .await?;
-
-    Ok(response)
-}
+        let cooldown = serde_json::from_str::<ApiResponse<ShipCooldown>>(&response_text)?.data;
+        Ok(Some(cooldown).filter(|cooldown| cooldown.remaining_seconds > 0))
+    }
 
-pub async fn orbit_ship(ship_symbol: &str) -> anyhow::Result<ApiResponse<OrbitResponse>> {
-    debug!("Attempting to orbit ship: {}", ship_symbol);
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .post(&format!("https://api.spacetraders.io/v2/my/ships/{}/orbit", ship_symbol))
-        .header("Authorization", format!("Bearer {}", token))
-        .json(&serde_json::json!({}))
-        .send()
-        .await?;
-        
-    let status = response.status();
-    debug!("Orbit response status: {}", status);
-    let response_text = response.text().await?;
-    debug!("Raw orbit response: {}", response_text);
-    
-    if !status.is_success() {
-        error!("Orbit request failed with status: {}", status);
-        if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-            error!("API Error: {} (Code: {})", error.error.message, error.error.code);
-            if let Some(data) = error.error.data {
-                debug!("Error data: {:?}", data);
-            }
-            anyhow::bail!("API Error: {}", error.error.message);
-        }
+    /// A ship still cooling down fails with `SpaceTradersError::Cooldown`,
+    /// which carries the remaining time.
+    pub async fn extract_resources(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<ExtractionResponse>> {
+        self.post(
+            &format!("/my/ships/{}/extract", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
     }
-    
-    match serde_json::from_str(&response_text) {
-        Ok(parsed) => {
-            debug!("Successfully parsed orbit response");
-            Ok(parsed)
-        },
-        Err(e) => {
-            error!("Failed to parse orbit response: {}", e);
-            error!("Response text was: {}", response_text);
-            Err(e.into())
-        }
+
+    /// Siphons gas at the ship's waypoint; requires a gas siphon mount, see `Ship::can_siphon`.
+    pub async fn siphon_resources(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<SiphonResponse>> {
+        self.post(
+            &format!("/my/ships/{}/siphon", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
     }
-}
 
-pub async fn navigate_ship(ship_symbol: &str, waypoint_symbol: &str) -> anyhow::Result<ApiResponse<NavigationResponse>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let request = NavigateRequest {
-        waypoint_symbol: waypoint_symbol.to_string(),
-    };
-    
-    let response = client
-        .post(&format!("https://api.spacetraders.io/v2/my/ships/{}/navigate", ship_symbol))
-        .header("Authorization", format!("Bearer {}", token))
-        .json(&request)
-        .send()
-        .await?;
-        
-    let status = response.status();
-    println!("Navigation response status: {}", status);
-    let response_text = response.text().await?;
-    println!("Raw response: {}", response_text);
-    
-    if !status.is_success() {
-        if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-            println!("Error: {}", error.error.message);
-            anyhow::bail!("API Error: {}", error.error.message);
-        }
+    /// Extracts from the deposits described by `survey`, which must belong to the ship's waypoint.
+    pub async fn extract_resources_with_survey(
+        &self,
+        ship_symbol: &str,
+        survey: &Survey,
+    ) -> anyhow::Result<ApiResponse<ExtractionResponse>> {
+        debug!(
+            "Extracting with survey {} at {} for ship {}",
+            survey.signature, survey.symbol, ship_symbol
+        );
+
+        self.post(&format!("/my/ships/{}/extract/survey", ship_symbol), survey)
+            .await
     }
-    
-    let parsed_response: ApiResponse<NavigationResponse> = serde_json::from_str(&response_text)
-        .map_err(|e| {
-            println!("JSON parsing error: {}", e);
-            e
-        })?;
-
-    Ok(parsed_response)
-}
 
-pub async fn dock_ship(ship_symbol: &str) -> anyhow::Result<ApiResponse<DockResponse>> {
-    debug!("Attempting to dock ship: {}", ship_symbol);
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .post(&format!("https://api.spacetraders.io/v2/my/ships/{}/dock", ship_symbol))
-        .header("Authorization", format!("Bearer {}", token))
-        .json(&serde_json::json!({}))
-        .send()
-        .await?;
-        
-    let status = response.status();
-    debug!("Dock response status: {}", status);
-    let response_text = response.text().await?;
-    debug!("Raw dock response: {}", response_text);
-    
-    if !status.is_success() {
-        error!("Dock request failed with status: {}", status);
-        if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-            error!("API Error: {} (Code: {})", error.error.message, error.error.code);
-            anyhow::bail!("API Error: {}", error.error.message);
-        }
+    /// Converts raw goods in the hold into `produce`, e.g. `TradeSymbol::Iron` from iron ore.
+    ///
+    /// Requires a refinery module; see `Ship::can_refine`.
+    pub async fn refine_ship(
+        &self,
+        ship_symbol: &str,
+        produce: &TradeSymbol,
+    ) -> anyhow::Result<ApiResponse<RefineResponse>> {
+        debug!("Attempting to refine {} on ship {}", produce, ship_symbol);
+        let request = RefineRequest {
+            produce: produce.clone(),
+        };
+
+        self.post(&format!("/my/ships/{}/refine", ship_symbol), &request)
+            .await
     }
-    
-    serde_json::from_str(&response_text).map_err(|e| {
-        error!("Failed to parse dock response: {}", e);
-        error!("Response text was: {}", response_text);
-        e.into()
-    })
-}
 
-pub async fn refuel_ship(ship_symbol: &str) -> anyhow::Result<ApiResponse<Ship>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .post(&format!("https://api.spacetraders.io/v2/my/ships/{}/refuel", ship_symbol))
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?
-        .json()
-        .await?;
-
-    Ok(response)
-}
+    /// Surveys the ship's waypoint; requires a surveyor mount and shares the extraction cooldown.
+    pub async fn create_survey(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<CreateSurveyResponse>> {
+        self.post(
+            &format!("/my/ships/{}/survey", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
+    }
 
-pub async fn check_extraction_cooldown(ship_symbol: &str) -> anyhow::Result<Option<CooldownData>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .get(&format!("https://api.spacetraders.io/v2/my/ships/{}/cooldown", ship_symbol))
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?;
-        
-    let status = response.status();
-    let response_text = response.text().await?;
-    
-    if status.is_success() {
-        if let Ok(cooldown_response) = serde_json::from_str::<ApiResponse<CooldownData>>(&response_text) {
-            return Ok(Some(cooldown_response.data));
-        }
+    /// Charts the ship's current waypoint, revealing its traits to every agent.
+    pub async fn chart_waypoint(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<ChartResponse>> {
+        debug!("Ship {} charting its waypoint", ship_symbol);
+        self.post(
+            &format!("/my/ships/{}/chart", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
     }
-    
-    Ok(None)
-}
 
-pub async fn extract_resources(ship_symbol: &str) -> anyhow::Result<ApiResponse<ExtractionResponse>> {
-    // First check if ship is on cooldown
-    if let Ok(Some(cooldown)) = check_extraction_cooldown(ship_symbol).await {
-        println!("Ship is on cooldown for {} more seconds (expires at {})",
-            cooldown.cooldown_remaining,
-            cooldown.expiration);
-        anyhow::bail!("Ship is on cooldown");
+    /// Scans for systems within range of the ship's sensors.
+    pub async fn scan_systems(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<ScanSystemsResponse>> {
+        self.scan(ship_symbol, "systems").await
     }
-    
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .post(&format!("https://api.spacetraders.io/v2/my/ships/{}/extract", ship_symbol))
-        .header("Authorization", format!("Bearer {}", token))
-        .json(&serde_json::json!({}))
-        .send()
-        .await?;
-        
-    let status = response.status();
-    println!("Extraction response status: {}", status);
-    let response_text = response.text().await?;
-    println!("Raw response: {}", response_text);
-    
-    if !status.is_success() {
-        if let Ok(error) = serde_json::from_str::<CooldownError>(&response_text) {
-            println!("Cooldown error: {}", error.error.message);
-            println!("Remaining cooldown: {} seconds", error.error.data.cooldown_remaining);
-            println!("Expires at: {}", error.error.data.expiration);
-            anyhow::bail!("Ship is on cooldown");
-        }
-        
-        if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-            println!("Error: {}", error.error.message);
-            anyhow::bail!("API Error: {}", error.error.message);
-        }
+
+    /// Scans for waypoints within range, including their traits.
+    pub async fn scan_waypoints(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<ScanWaypointsResponse>> {
+        self.scan(ship_symbol, "waypoints").await
     }
-    
-    let parsed_response: ApiResponse<ExtractionResponse> = serde_json::from_str(&response_text)
-        .map_err(|e| {
-            println!("JSON parsing error: {}", e);
-            e
-        })?;
-
-    Ok(parsed_response)
-}
 
-pub async fn purchase_ship(ship_type: &str, waypoint_symbol: &str) -> anyhow::Result<ApiResponse<PurchaseShipResponse>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let request = PurchaseShipRequest {
-        ship_type: ship_type.to_string(),
-        waypoint_symbol: waypoint_symbol.to_string(),
-    };
-    
-    let response = client
-        .post("https://api.spacetraders.io/v2/my/ships")
-        .header("Authorization", format!("Bearer {}", token))
-        .json(&request)
-        .send()
-        .await?;
-        
-    println!("Purchase response status: {}", response.status());
-    
-    let response_text = response.text().await?;
-    println!("Raw response: {}", response_text);
-    
-    let parsed_response: ApiResponse<PurchaseShipResponse> = serde_json::from_str(&response_text)
-        .map_err(|e| {
-            println!("JSON parsing error: {}", e);
-            e
-        })?;
-
-    Ok(parsed_response)
-}
+    /// Scans for other agents' ships within range.
+    pub async fn scan_ships(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<ScanShipsResponse>> {
+        self.scan(ship_symbol, "ships").await
+    }
 
-pub async fn get_market_data(system_symbol: &str, waypoint_symbol: &str) -> anyhow::Result<ApiResponse<Market>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .get(&format!(
-            "https://api.spacetraders.io/v2/systems/{}/waypoints/{}/market",
+    /// Scans require a sensor array and put the ship on cooldown.
+    async fn scan<T: DeserializeOwned>(
+        &self,
+        ship_symbol: &str,
+        target: &str,
+    ) -> anyhow::Result<T> {
+        debug!("Ship {} scanning for {}", ship_symbol, target);
+
+        self.post(
+            &format!("/my/ships/{}/scan/{}", ship_symbol, target),
+            &serde_json::json!({}),
+        )
+        .await
+    }
+
+    pub async fn purchase_ship(
+        &self,
+        ship_type: &str,
+        waypoint_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<PurchaseShipResponse>> {
+        let request = PurchaseShipRequest {
+            ship_type: ship_type.to_string(),
+            waypoint_symbol: waypoint_symbol.to_string(),
+        };
+
+        self.post("/my/ships", &request).await
+    }
+
+    pub async fn get_market_data(
+        &self,
+        system_symbol: &str,
+        waypoint_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<Market>> {
+        self.get(&format!(
+            "/systems/{}/waypoints/{}/market",
             system_symbol, waypoint_symbol
         ))
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?;
-        
-    let status = response.status();
-    let response_text = response.text().await?;
-    
-    if !status.is_success() {
-        if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-            println!("Error: {}", error.error.message);
-            anyhow::bail!("API Error: {}", error.error.message);
-        }
+        .await
     }
-    
-    let parsed_response = serde_json::from_str(&response_text)?;
-    Ok(parsed_response)
-}
 
-pub async fn get_ship_cargo(ship_symbol: &str) -> anyhow::Result<ApiResponse<CargoResponse>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let response = client
-        .get(&format!(
-            "https://api.spacetraders.io/v2/my/ships/{}/cargo",
-            ship_symbol
-        ))
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?;
-        
-    let status = response.status();
-    let response_text = response.text().await?;
-    
-    if !status.is_success() {
-        if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-            println!("Error: {}", error.error.message);
-            anyhow::bail!("API Error: {}", error.error.message);
-        }
+    pub async fn get_ship_cargo(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<CargoResponse>> {
+        self.get(&format!("/my/ships/{}/cargo", ship_symbol)).await
     }
-    
-    let parsed_response = serde_json::from_str(&response_text)?;
-    Ok(parsed_response)
-}
 
-pub async fn sell_cargo_item(ship_symbol: &str, item_symbol: &str, units: i32) -> anyhow::Result<ApiResponse<SellCargoResponse>> {
-    debug!("Attempting to sell {} units of {} from ship {}", units, item_symbol, ship_symbol);
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let request = SellCargoRequest {
-        symbol: item_symbol.to_string(),
-        units,
-    };
-    
-    let response = client
-        .post(&format!("https://api.spacetraders.io/v2/my/ships/{}/sell", ship_symbol))
-        .header("Authorization", format!("Bearer {}", token))
-        .json(&request)
-        .send()
-        .await?;
-        
-    let status = response.status();
-    debug!("Sell response status: {}", status);
-    let response_text = response.text().await?;
-    debug!("Raw sell response: {}", response_text);
-    
-    if !status.is_success() {
-        error!("Sell request failed with status: {}", status);
-        if let Ok(error) = serde_json::from_str::<ErrorResponse>(&response_text) {
-            error!("API Error: {} (Code: {})", error.error.message, error.error.code);
-            anyhow::bail!("API Error: {}", error.error.message);
-        }
+    pub async fn sell_cargo_item(
+        &self,
+        ship_symbol: &str,
+        item_symbol: &TradeSymbol,
+        units: i32,
+    ) -> anyhow::Result<ApiResponse<SellCargoResponse>> {
+        debug!(
+            "Attempting to sell {} units of {} from ship {}",
+            units, item_symbol, ship_symbol
+        );
+        let request = SellCargoRequest {
+            symbol: item_symbol.clone(),
+            units,
+        };
+
+        self.post(&format!("/my/ships/{}/sell", ship_symbol), &request)
+            .await
+    }
+
+    /// Buys goods at the market where the ship is docked.
+    pub async fn purchase_cargo(
+        &self,
+        ship_symbol: &str,
+        item_symbol: &TradeSymbol,
+        units: i32,
+    ) -> anyhow::Result<ApiResponse<PurchaseCargoResponse>> {
+        debug!(
+            "Attempting to purchase {} units of {} for ship {}",
+            units, item_symbol, ship_symbol
+        );
+        let request = PurchaseCargoRequest {
+            symbol: item_symbol.clone(),
+            units,
+        };
+
+        self.post(&format!("/my/ships/{}/purchase", ship_symbol), &request)
+            .await
+    }
+
+    /// Dumps cargo into space; the goods are lost.
+    pub async fn jettison_cargo(
+        &self,
+        ship_symbol: &str,
+        item_symbol: &TradeSymbol,
+        units: i32,
+    ) -> anyhow::Result<ApiResponse<JettisonResponse>> {
+        debug!(
+            "Attempting to jettison {} units of {} from ship {}",
+            units, item_symbol, ship_symbol
+        );
+        let request = JettisonRequest {
+            symbol: item_symbol.clone(),
+            units,
+        };
+
+        self.post(&format!("/my/ships/{}/jettison", ship_symbol), &request)
+            .await
+    }
+
+    /// Moves cargo to another ship at the same waypoint; both ships must share a nav status.
+    pub async fn transfer_cargo(
+        &self,
+        ship_symbol: &str,
+        target_ship_symbol: &str,
+        item_symbol: &TradeSymbol,
+        units: i32,
+    ) -> anyhow::Result<ApiResponse<TransferCargoResponse>> {
+        debug!(
+            "Attempting to transfer {} units of {} from ship {} to {}",
+            units, item_symbol, ship_symbol, target_ship_symbol
+        );
+        let request = TransferCargoRequest {
+            trade_symbol: item_symbol.clone(),
+            units,
+            ship_symbol: target_ship_symbol.to_string(),
+        };
+
+        self.post(&format!("/my/ships/{}/transfer", ship_symbol), &request)
+            .await
     }
-    
-    serde_json::from_str(&response_text).map_err(|e| {
-        error!("Failed to parse sell response: {}", e);
-        error!("Response text was: {}", response_text);
-        e.into()
-    })
-} 
\ No newline at end of file
+}
@@ -1,159 +1,127 @@
-use serde::{Deserialize, Serialize};
-use crate::api::ApiResponse;
-
-#[derive(Debug, Deserialize)]
-pub struct Waypoint {
-    pub symbol: String,
-    pub r#type: String,
-    pub x: i32,
-    pub y: i32,
-    pub traits: Vec<WaypointTrait>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct WaypointTrait {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct Shipyard {
-    pub symbol: String,
-    #[serde(rename = "shipTypes")]
-    pub ship_types: Vec<ShipType>,
-    pub ships: Option<Vec<Ship>>,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipType {
-    pub r#type: String,
+use crate::api::models::{
+    ApiResponse, RepairShipResponse, ScrapShipResponse, ShipModulesResponse, ShipMountsResponse,
+    ShipServicePriceResponse, Shipyard, TradeSymbol, Waypoint, WaypointTraitSymbol,
+};
+use crate::api::{SpaceTradersClient, WaypointFilters};
+use log::debug;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Names the mount or module to install or remove, e.g. `MOUNT_MINING_LASER_II`.
+#[derive(Debug, Serialize)]
+pub struct ShipComponentRequest {
+    pub symbol: TradeSymbol,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct Ship {
-    pub r#type: String,
-    pub name: String,
-    pub description: String,
-    #[serde(rename = "purchasePrice")]
-    pub purchase_price: i32,
-    pub frame: ShipFrame,
-    pub reactor: ShipReactor,
-    pub engine: ShipEngine,
-    pub modules: Vec<ShipModule>,
-    pub mounts: Vec<ShipMount>,
+impl SpaceTradersClient {
+    pub async fn find_shipyards(&self, system_symbol: &str) -> anyhow::Result<Vec<Waypoint>> {
+        let filters = WaypointFilters::new().with_trait(WaypointTraitSymbol::Shipyard);
+        self.list_waypoints(system_symbol, &filters).await
+    }
+
+    pub async fn get_shipyard_details(
+        &self,
+        system_symbol: &str,
+        waypoint_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<Shipyard>> {
+        self.get(&format!(
+            "/systems/{}/waypoints/{}/shipyard",
+            system_symbol, waypoint_symbol
+        ))
+        .await
+    }
+
+    /// Installs a mount from the ship's cargo; the ship must be docked at a shipyard.
+    pub async fn install_mount(
+        &self,
+        ship_symbol: &str,
+        mount_symbol: &TradeSymbol,
+    ) -> anyhow::Result<ApiResponse<ShipMountsResponse>> {
+        self.modify_ship(ship_symbol, "mounts/install", mount_symbol)
+            .await
+    }
+
+    /// Removes a mount into the ship's cargo; the ship must be docked at a shipyard.
+    pub async fn remove_mount(
+        &self,
+        ship_symbol: &str,
+        mount_symbol: &TradeSymbol,
+    ) -> anyhow::Result<ApiResponse<ShipMountsResponse>> {
+        self.modify_ship(ship_symbol, "mounts/remove", mount_symbol)
+            .await
+    }
+
+    /// Installs a module from the ship's cargo; the ship must be docked at a shipyard.
+    pub async fn install_module(
+        &self,
+        ship_symbol: &str,
+        module_symbol: &TradeSymbol,
+    ) -> anyhow::Result<ApiResponse<ShipModulesResponse>> {
+        self.modify_ship(ship_symbol, "modules/install", module_symbol)
+            .await
+    }
+
+    /// Removes a module into the ship's cargo; the ship must be docked at a shipyard.
+    pub async fn remove_module(
+        &self,
+        ship_symbol: &str,
+        module_symbol: &TradeSymbol,
+    ) -> anyhow::Result<ApiResponse<ShipModulesResponse>> {
+        self.modify_ship(ship_symbol, "modules/remove", module_symbol)
+            .await
+    }
+
+    async fn modify_ship<T: DeserializeOwned>(
+        &self,
+        ship_symbol: &str,
+        action: &str,
+        component_symbol: &TradeSymbol,
+    ) -> anyhow::Result<T> {
+        debug!("Ship {}: {} {}", ship_symbol, action, component_symbol);
+        let request = ShipComponentRequest {
+            symbol: component_symbol.clone(),
+        };
+
+        self.post(&format!("/my/ships/{}/{}", ship_symbol, action), &request)
+            .await
+    }
+
+    /// Quotes a full repair at the shipyard where the ship is docked.
+    pub async fn get_repair_price(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<ShipServicePriceResponse>> {
+        self.get(&format!("/my/ships/{}/repair", ship_symbol)).await
+    }
+
+    pub async fn repair_ship(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<RepairShipResponse>> {
+        self.post(
+            &format!("/my/ships/{}/repair", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
+    }
+
+    /// Quotes what scrapping the ship at its current shipyard would pay.
+    pub async fn get_scrap_price(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<ShipServicePriceResponse>> {
+        self.get(&format!("/my/ships/{}/scrap", ship_symbol)).await
+    }
+
+    /// Scraps the ship for credits; it is removed from the fleet.
+    pub async fn scrap_ship(
+        &self,
+        ship_symbol: &str,
+    ) -> anyhow::Result<ApiResponse<ScrapShipResponse>> {
+        self.post(
+            &format!("/my/ships/{}/scrap", ship_symbol),
+            &serde_json::json!({}),
+        )
+        .await
+    }
 }
-
-#[derive(Debug, Deserialize)]
-pub struct ShipFrame {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipReactor {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipEngine {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipModule {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-}
-
-#[derive(Debug, Deserialize)]
-pub struct ShipMount {
-    pub symbol: String,
-    pub name: String,
-    pub description: String,
-}
-
-#[derive(Serialize)]
-pub struct PurchaseShipRequest {
-    #[serde(rename = "shipType")]
-    pub ship_type: String,
-    #[serde(rename = "waypointSymbol")]
-    pub waypoint_symbol: String,
-}
-
-pub async fn find_shipyards(system_symbol: &str) -> anyhow::Result<ApiResponse<Vec<Waypoint>>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let url = format!(
-        "https://api.spacetraders.io/v2/systems/{}/waypoints?traits=SHIPYARD",
-        system_symbol
-    );
-    
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?
-        .json()
-        .await?;
-
-    Ok(response)
-}
-
-pub async fn get_shipyard_details(system_symbol: &str, waypoint_symbol: &str) -> anyhow::Result<ApiResponse<Shipyard>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let url = format!(
-        "https://api.spacetraders.io/v2/systems/{}/waypoints/{}/shipyard",
-        system_symbol,
-        waypoint_symbol
-    );
-    
-    let response = client
-        .get(&url)
-        .header("Authorization", format!("Bearer {}", token))
-        .send()
-        .await?
-        .json()
-        .await?;
-
-    Ok(response)
-}
-
-pub async fn purchase_ship(ship_type: &str, waypoint_symbol: &str) -> anyhow::Result<ApiResponse<Ship>> {
-    let token = std::env::var("BEARER_TOKEN")?;
-    
-    let client = reqwest::Client::new();
-    let request = PurchaseShipRequest {
-        ship_type: ship_type.to_string(),
-        waypoint_symbol: waypoint_symbol.to_string(),
-    };
-    
-    let response = client
-        .post("https://api.spacetraders.io/v2/my/ships")
-        .header("Authorization", format!("Bearer {}", token))
-        .json(&request)
-        .send()
-        .await?;
-        
-    println!("Purchase response status: {}", response.status());
-    
-    let response_text = response.text().await?;
-    println!("Raw response: {}", response_text);
-    
-    let parsed_response: ApiResponse<Ship> = serde_json::from_str(&response_text)
-        .map_err(|e| {
-            println!("JSON parsing error: {}", e);
-            e
-        })?;
-
-    Ok(parsed_response)
-} 
\ No newline at end of file
@@ -0,0 +1,10 @@
+use crate::api::models::ServerStatus;
+use crate::api::SpaceTradersClient;
+use anyhow::Result;
+
+impl SpaceTradersClient {
+    /// Fetches the server status, leaderboards and reset schedule; no token is needed.
+    pub async fn get_status(&self) -> Result<ServerStatus> {
+        self.get("/").await
+    }
+}
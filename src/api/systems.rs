@@ -0,0 +1,192 @@
+use crate::api::models::{
+    ApiResponse, Construction, JumpGate, SupplyConstructionResponse, TradeSymbol, Waypoint,
+    WaypointTraitSymbol, WaypointType,
+};
+use crate::api::SpaceTradersClient;
+use anyhow::Result;
+use log::debug;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct SupplyConstructionRequest {
+    #[serde(rename = "shipSymbol")]
+    pub ship_symbol: String,
+    #[serde(rename = "tradeSymbol")]
+    pub trade_symbol: TradeSymbol,
+    pub units: i32,
+}
+
+/// Narrows `list_waypoints` to waypoints with every given trait and, optionally, one type.
+#[derive(Debug, Clone, Default)]
+pub struct WaypointFilters {
+    pub traits: Vec<WaypointTraitSymbol>,
+    pub waypoint_type: Option<WaypointType>,
+}
+
+impl WaypointFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_trait(mut self, symbol: WaypointTraitSymbol) -> Self {
+        self.traits.push(symbol);
+        self
+    }
+
+    pub fn with_type(mut self, waypoint_type: WaypointType) -> Self {
+        self.waypoint_type = Some(waypoint_type);
+        self
+    }
+
+    /// The filters as query parameters, one `traits` pair per trait.
+    pub(crate) fn query_pairs(&self) -> Vec<(&'static str, String)> {
+        self.traits
+            .iter()
+            .map(|symbol| ("traits", symbol.to_string()))
+            .chain(
+                self.waypoint_type
+                    .iter()
+                    .map(|waypoint_type| ("type", waypoint_type.to_string())),
+            )
+            .collect()
+    }
+}
+
+/// The system a waypoint belongs to, e.g. `X1-PS34` for `X1-PS34-A1`.
+pub fn system_symbol_of(waypoint_symbol: &str) -> &str {
+    waypoint_symbol
+        .rsplit_once('-')
+        .map_or(waypoint_symbol, |(system, _)| system)
+}
+
+impl SpaceTradersClient {
+    /// Fetches every waypoint in the system matching `filters`, following pagination.
+    pub async fn list_waypoints(
+        &self,
+        system_symbol: &str,
+        filters: &WaypointFilters,
+    ) -> Result<Vec<Waypoint>> {
+        self.get_all_with_query(
+            &format!("/systems/{}/waypoints", system_symbol),
+            filters.query_pairs(),
+        )
+        .await
+    }
+
+    pub async fn get_waypoint_details(
+        &self,
+        system_symbol: &str,
+        waypoint_symbol: &str,
+    ) -> Result<ApiResponse<Waypoint>> {
+        self.get(&format!(
+            "/systems/{}/waypoints/{}",
+            system_symbol, waypoint_symbol
+        ))
+        .await
+    }
+
+    /// Lists the systems reachable through the jump gate at `waypoint_symbol`.
+    pub async fn get_jump_gate(
+        &self,
+        system_symbol: &str,
+        waypoint_symbol: &str,
+    ) -> Result<ApiResponse<JumpGate>> {
+        self.get(&format!(
+            "/systems/{}/waypoints/{}/jump-gate",
+            system_symbol, waypoint_symbol
+        ))
+        .await
+    }
+
+    /// Materials required and delivered so far for a waypoint under construction.
+    pub async fn get_construction(
+        &self,
+        system_symbol: &str,
+        waypoint_symbol: &str,
+    ) -> Result<ApiResponse<Construction>> {
+        self.get(&format!(
+            "/systems/{}/waypoints/{}/construction",
+            system_symbol, waypoint_symbol
+        ))
+        .await
+    }
+
+    /// Delivers cargo to a construction site; the ship must be docked at `waypoint_symbol`.
+    pub async fn supply_construction(
+        &self,
+        ship_symbol: &str,
+        waypoint_symbol: &str,
+        item_symbol: &TradeSymbol,
+        units: i32,
+    ) -> Result<ApiResponse<SupplyConstructionResponse>> {
+        debug!(
+            "Supplying {} units of {} from ship {} to {}",
+            units, item_symbol, ship_symbol, waypoint_symbol
+        );
+        let request = SupplyConstructionRequest {
+            ship_symbol: ship_symbol.to_string(),
+            trade_symbol: item_symbol.clone(),
+            units,
+        };
+
+        self.post(
+            &format!(
+                "/systems/{}/waypoints/{}/construction/supply",
+                system_symbol_of(waypoint_symbol),
+                waypoint_symbol
+            ),
+            &request,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_filters_build_no_query_pairs() {
+        assert!(WaypointFilters::new().query_pairs().is_empty());
+    }
+
+    #[test]
+    fn each_trait_is_its_own_parameter() {
+        let filters = WaypointFilters::new()
+            .with_trait(WaypointTraitSymbol::Marketplace)
+            .with_trait(WaypointTraitSymbol::Shipyard);
+        assert_eq!(
+            filters.query_pairs(),
+            vec![
+                ("traits", "MARKETPLACE".to_string()),
+                ("traits", "SHIPYARD".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn traits_and_type_are_combined() {
+        let filters = WaypointFilters::new()
+            .with_type(WaypointType::EngineeredAsteroid)
+            .with_trait(WaypointTraitSymbol::Marketplace);
+        assert_eq!(
+            filters.query_pairs(),
+            vec![
+                ("traits", "MARKETPLACE".to_string()),
+                ("type", "ENGINEERED_ASTEROID".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_values_are_url_encoded() {
+        let client = SpaceTradersClient::new("token");
+        let filters = WaypointFilters::new().with_trait(WaypointTraitSymbol::from("A&B=C"));
+        let request = client
+            .request(reqwest::Method::GET, "/systems/X1-TEST/waypoints")
+            .query(&filters.query_pairs())
+            .build()
+            .unwrap();
+        assert_eq!(request.url().query(), Some("traits=A%26B%3DC"));
+    }
+}
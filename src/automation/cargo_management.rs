@@ -0,0 +1,206 @@
+use crate::api::{
+    CargoItem, SpaceTradersClient, TradeSymbol, Waypoint, WaypointFilters, WaypointTraitSymbol,
+};
+use anyhow::Result;
+use log::error;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct ContractRequirement {
+    pub trade_symbol: TradeSymbol,
+    pub required_units: i32,
+    pub fulfilled_units: i32,
+    pub destination: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MarketPriceInfo {
+    pub waypoint: String,
+    pub sell_price: i32,
+    pub trade_volume: i32,
+    pub distance: f64,
+}
+#[derive(Debug)]
+pub struct MarketAnalysis {
+    pub prices: HashMap<TradeSymbol, Vec<MarketPriceInfo>>,
+    pub requirements: Vec<ContractRequirement>,
+}
+
+impl MarketAnalysis {
+    pub fn get_best_market_for_item(&self, item_symbol: &TradeSymbol) -> Option<&MarketPriceInfo> {
+        self.prices
+            .get(item_symbol)?
+            .iter()
+            .max_by_key(|m| (m.sell_price as f64 - m.distance * 100.0) as i32)
+    }
+
+    pub fn get_contract_requirement(
+        &self,
+        item_symbol: &TradeSymbol,
+    ) -> Option<&ContractRequirement> {
+        self.requirements
+            .iter()
+            .find(|req| &req.trade_symbol == item_symbol)
+    }
+}
+
+pub async fn get_contract_requirements(
+    client: &SpaceTradersClient,
+) -> Result<Vec<ContractRequirement>> {
+    let mut requirements = Vec::new();
+
+    match client.list_contracts().await {
+        Ok(contracts) => {
+            for contract in &contracts {
+                if contract.accepted && !contract.fulfilled {
+                    for (delivery, remaining) in contract.outstanding_deliveries() {
+                        requirements.push(ContractRequirement {
+                            trade_symbol: delivery.trade_symbol.clone(),
+                            required_units: remaining,
+                            fulfilled_units: delivery.units_fulfilled,
+                            destination: delivery.destination_symbol.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            error!("Failed to get contract requirements: {}", e);
+        }
+    }
+
+    Ok(requirements)
+}
+
+/// Every waypoint in the system with a marketplace.
+pub async fn find_market_waypoints(
+    client: &SpaceTradersClient,
+    system_symbol: &str,
+) -> Result<Vec<Waypoint>> {
+    let filters = WaypointFilters::new().with_trait(WaypointTraitSymbol::Marketplace);
+    client.list_waypoints(system_symbol, &filters).await
+}
+
+/// Symbols of every waypoint in the system with a marketplace.
+pub async fn find_all_markets_in_system(
+    client: &SpaceTradersClient,
+    system_symbol: &str,
+) -> Result<Vec<String>> {
+    let waypoints = find_market_waypoints(client, system_symbol).await?;
+    Ok(waypoints
+        .into_iter()
+        .map(|waypoint| waypoint.symbol)
+        .collect())
+}
+
+/// Returns `current_waypoint` if it has a marketplace.
+pub async fn find_nearest_market(
+    client: &SpaceTradersClient,
+    system_symbol: &str,
+    current_waypoint: &str,
+) -> Result<Option<String>> {
+    // Get waypoint details to check for marketplace
+    match client
+        .get_waypoint_details(system_symbol, current_waypoint)
+        .await
+    {
+        Ok(response) => {
+            let has_marketplace = response
+                .data
+                .traits
+                .iter()
+                .any(|trait_| trait_.symbol == WaypointTraitSymbol::Marketplace);
+
+            if has_marketplace {
+                return Ok(Some(current_waypoint.to_string()));
+            }
+        }
+        Err(e) => {
+            error!("Failed to get waypoint details: {}", e);
+        }
+    }
+
+    // If current waypoint doesn't have a marketplace, look for nearest one
+    // You would need to implement the logic to find other waypoints with markets
+    // For now, we'll return None to indicate no market was found
+    Ok(None)
+}
+
+// current market prices - this will be helpful for selling decisions
+pub async fn get_current_market_prices(
+    client: &SpaceTradersClient,
+    system_symbol: &str,
+    waypoint_symbol: &str,
+) -> Result<Vec<(TradeSymbol, i32)>> {
+    match client.get_market_data(system_symbol, waypoint_symbol).await {
+        Ok(market_response) => {
+            let prices: Vec<(TradeSymbol, i32)> = market_response
+                .data
+                .trade_goods
+                .iter()
+                .map(|good| (good.symbol.clone(), good.sell_price))
+                .collect();
+            Ok(prices)
+        }
+        Err(e) => {
+            error!("Failed to get market prices: {}", e);
+            Err(e)
+        }
+    }
+}
+
+pub async fn analyze_markets(
+    client: &SpaceTradersClient,
+    system_symbol: &str,
+    current_x: i32,
+    current_y: i32,
+    items: &[TradeSymbol],
+) -> Result<MarketAnalysis> {
+    let mut all_prices: HashMap<TradeSymbol, Vec<MarketPriceInfo>> = HashMap::new();
+
+    let markets = find_market_waypoints(client, system_symbol).await?;
+    for market_waypoint in markets {
+        let distance = (((market_waypoint.x - current_x).pow(2)
+            + (market_waypoint.y - current_y).pow(2)) as f64)
+            .sqrt();
+
+        if let Ok(market_data) = client
+            .get_market_data(system_symbol, &market_waypoint.symbol)
+            .await
+        {
+            for trade_good in market_data.data.trade_goods {
+                if items.contains(&trade_good.symbol) {
+                    let price_info = MarketPriceInfo {
+                        waypoint: market_waypoint.symbol.clone(),
+                        sell_price: trade_good.sell_price,
+                        trade_volume: trade_good.trade_volume,
+                        distance,
+                    };
+
+                    all_prices
+                        .entry(trade_good.symbol)
+                        .or_default()
+                        .push(price_info);
+                }
+            }
+        }
+    }
+
+    let requirements = get_contract_requirements(client).await?;
+
+    Ok(MarketAnalysis {
+        prices: all_prices,
+        requirements,
+    })
+}
+
+pub fn calculate_max_sellable_units(
+    item: &CargoItem,
+    contract_requirement: Option<&ContractRequirement>,
+) -> i32 {
+    if let Some(req) = contract_requirement {
+        (item.units - req.required_units).max(0)
+    } else {
+        item.units
+    }
+}
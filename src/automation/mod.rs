@@ -0,0 +1,13 @@
+//! Higher-level routines built on top of the raw endpoints in [`crate::api`].
+mod cargo_management;
+mod reputation;
+mod survey_store;
+
+// Public re-exports
+pub use self::cargo_management::{
+    analyze_markets, calculate_max_sellable_units, find_all_markets_in_system,
+    find_market_waypoints, find_nearest_market, get_contract_requirements,
+    get_current_market_prices, ContractRequirement, MarketAnalysis, MarketPriceInfo,
+};
+pub use self::reputation::reputation_changes;
+pub use self::survey_store::SurveyStore;
@@ -0,0 +1,20 @@
+use crate::api::{AgentFaction, FactionSymbol};
+
+/// How reputation moved between two `get_my_factions` snapshots, e.g. taken
+/// before and after fulfilling a contract. Unchanged factions are left out.
+pub fn reputation_changes(
+    before: &[AgentFaction],
+    after: &[AgentFaction],
+) -> Vec<(FactionSymbol, i32)> {
+    after
+        .iter()
+        .map(|current| {
+            let previous = before
+                .iter()
+                .find(|faction| faction.symbol == current.symbol)
+                .map_or(0, |faction| faction.reputation);
+            (current.symbol.clone(), current.reputation - previous)
+        })
+        .filter(|(_, change)| *change != 0)
+        .collect()
+}
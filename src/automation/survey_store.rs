@@ -0,0 +1,225 @@
+use crate::api::{SpaceTradersError, Survey, TradeSymbol};
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+
+/// Surveys collected by the fleet, kept until they expire or are used up.
+#[derive(Debug, Clone, Default)]
+pub struct SurveyStore {
+    surveys: Vec<Survey>,
+}
+
+impl SurveyStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.surveys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.surveys.is_empty()
+    }
+
+    /// Adds surveys, replacing any stored survey with the same signature.
+    pub fn insert(&mut self, surveys: impl IntoIterator<Item = Survey>) {
+        for survey in surveys {
+            self.remove(&survey.signature);
+            self.surveys.push(survey);
+        }
+    }
+
+    pub fn remove(&mut self, signature: &str) -> Option<Survey> {
+        let index = self
+            .surveys
+            .iter()
+            .position(|survey| survey.signature == signature)?;
+        Some(self.surveys.swap_remove(index))
+    }
+
+    /// Drops every survey that has expired by `now`, returning how many were removed.
+    pub fn prune_expired(&mut self, now: DateTime<Utc>) -> usize {
+        let before = self.surveys.len();
+        self.surveys.retain(|survey| !survey.is_expired(now));
+        before - self.surveys.len()
+    }
+
+    /// Drops the survey if `err` says the server will no longer accept it.
+    ///
+    /// Returns whether the survey was discarded, so callers can fall back to
+    /// plain extraction.
+    pub fn discard_if_unusable(&mut self, signature: &str, err: &anyhow::Error) -> bool {
+        match err.downcast_ref::<SpaceTradersError>() {
+            Some(SpaceTradersError::SurveyUnusable(_)) => self.remove(signature).is_some(),
+            _ => false,
+        }
+    }
+
+    /// Unexpired surveys for `waypoint` with at least one wanted deposit, best first.
+    ///
+    /// Surveys are ranked by how many deposits are wanted, then by the share of
+    /// wanted deposits so smaller surveys with less junk win ties.
+    pub fn ranked(
+        &self,
+        waypoint: &str,
+        wanted: &[TradeSymbol],
+        now: DateTime<Utc>,
+    ) -> Vec<&Survey> {
+        let mut ranked: Vec<(&Survey, usize)> = self
+            .surveys
+            .iter()
+            .filter(|survey| survey.symbol == waypoint && !survey.is_expired(now))
+            .map(|survey| (survey, survey.matching_deposits(wanted)))
+            .filter(|(_, matches)| *matches > 0)
+            .collect();
+
+        ranked.sort_by(|(a, a_matches), (b, b_matches)| {
+            compare_matches(b, *b_matches, a, *a_matches)
+        });
+        ranked.into_iter().map(|(survey, _)| survey).collect()
+    }
+
+    pub fn best(
+        &self,
+        waypoint: &str,
+        wanted: &[TradeSymbol],
+        now: DateTime<Utc>,
+    ) -> Option<&Survey> {
+        self.ranked(waypoint, wanted, now).into_iter().next()
+    }
+}
+
+fn compare_matches(a: &Survey, a_matches: usize, b: &Survey, b_matches: usize) -> Ordering {
+    // Cross-multiplied so the ratio comparison stays in integers.
+    a_matches
+        .cmp(&b_matches)
+        .then_with(|| (a_matches * b.deposits.len()).cmp(&(b_matches * a.deposits.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{ErrorDetail, SurveyDeposit, SurveySize};
+    use chrono::Duration;
+
+    const WAYPOINT: &str = "X1-A1-B2";
+
+    fn survey(signature: &str, deposits: &[TradeSymbol], expiration: DateTime<Utc>) -> Survey {
+        Survey {
+            signature: signature.to_string(),
+            symbol: WAYPOINT.to_string(),
+            deposits: deposits
+                .iter()
+                .map(|symbol| SurveyDeposit {
+                    symbol: symbol.clone(),
+                })
+                .collect(),
+            expiration: expiration.to_rfc3339(),
+            size: SurveySize::Small,
+        }
+    }
+
+    fn signatures(surveys: Vec<&Survey>) -> Vec<&str> {
+        surveys
+            .into_iter()
+            .map(|survey| survey.signature.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn ranks_by_wanted_deposits_then_wanted_share() {
+        use TradeSymbol::{CopperOre, IronOre, QuartzSand as Q};
+        let now = Utc::now();
+        let later = now + Duration::hours(1);
+        let mut store = SurveyStore::new();
+        store.insert([
+            // One wanted deposit.
+            survey("one", &[IronOre, Q, Q], later),
+            // Two wanted deposits out of four.
+            survey("two-of-four", &[IronOre, IronOre, Q, Q], later),
+            // Two wanted deposits out of three: same count, better share.
+            survey("two-of-three", &[IronOre, CopperOre, Q], later),
+            // Nothing wanted.
+            survey("junk", &[Q, Q], later),
+        ]);
+
+        let ranked = store.ranked(WAYPOINT, &[IronOre, CopperOre], now);
+        assert_eq!(
+            signatures(ranked),
+            vec!["two-of-three", "two-of-four", "one"]
+        );
+        assert_eq!(
+            store
+                .best(WAYPOINT, &[IronOre, CopperOre], now)
+                .map(|survey| survey.signature.as_str()),
+            Some("two-of-three")
+        );
+    }
+
+    #[test]
+    fn ranking_skips_other_waypoints_and_expired_surveys() {
+        let now = Utc::now();
+        let mut elsewhere = survey(
+            "elsewhere",
+            &[TradeSymbol::IronOre],
+            now + Duration::hours(1),
+        );
+        elsewhere.symbol = "X1-A1-C3".to_string();
+        let mut store = SurveyStore::new();
+        store.insert([
+            elsewhere,
+            survey(
+                "expired",
+                &[TradeSymbol::IronOre],
+                now - Duration::seconds(1),
+            ),
+            survey("fresh", &[TradeSymbol::IronOre], now + Duration::hours(1)),
+        ]);
+
+        let ranked = store.ranked(WAYPOINT, &[TradeSymbol::IronOre], now);
+        assert_eq!(signatures(ranked), vec!["fresh"]);
+    }
+
+    #[test]
+    fn prune_expired_removes_only_expired_surveys() {
+        let now = Utc::now();
+        let mut store = SurveyStore::new();
+        store.insert([
+            survey(
+                "expired",
+                &[TradeSymbol::IronOre],
+                now - Duration::minutes(5),
+            ),
+            survey("expiring-now", &[TradeSymbol::IronOre], now),
+            survey("fresh", &[TradeSymbol::IronOre], now + Duration::minutes(5)),
+        ]);
+
+        assert_eq!(store.prune_expired(now), 2);
+        assert_eq!(store.len(), 1);
+        assert!(store.remove("fresh").is_some());
+    }
+
+    #[test]
+    fn discard_if_unusable_only_drops_on_survey_errors() {
+        let now = Utc::now();
+        let mut store = SurveyStore::new();
+        store.insert([survey(
+            "s",
+            &[TradeSymbol::IronOre],
+            now + Duration::hours(1),
+        )]);
+
+        let detail = |code| ErrorDetail {
+            message: "error".to_string(),
+            code,
+            data: None,
+        };
+        let other: anyhow::Error = SpaceTradersError::from_detail(detail(4228)).into();
+        assert!(!store.discard_if_unusable("s", &other));
+        assert_eq!(store.len(), 1);
+
+        let exhausted: anyhow::Error = SpaceTradersError::from_detail(detail(4224)).into();
+        assert!(store.discard_if_unusable("s", &exhausted));
+        assert!(store.is_empty());
+    }
+}
@@ -1 +1,8 @@
-pub mod api;
\ No newline at end of file
+//! Client library for the SpaceTraders API.
+//!
+//! [`api`] wraps the HTTP endpoints behind a shared [`SpaceTradersClient`];
+//! [`automation`] holds the market and contract helpers built on top of it.
+pub mod api;
+pub mod automation;
+
+pub use api::{ApiResponse, Paginated, SpaceTradersClient, SpaceTradersError};
@@ -1,13 +1,11 @@
 use anyhow::Result;
-use env_logger;
 use log::{debug, error, info};
 use spacetraders_client::api::{
-    accept_contract, analyze_markets, calculate_max_sellable_units, dock_ship,
-    find_all_markets_in_system, find_asteroids, find_shipyards, get_agent_details,
-    get_contract_requirements, get_market_data, get_ship_cargo, get_shipyard_details,
-    get_waypoint_details, list_contracts, list_ships, navigate_ship, orbit_ship, purchase_ship,
-    sell_cargo_item, CargoItem, ContractRequirement, MarketAnalysis, MarketPriceInfo, Ship,
+    save_token, system_symbol_of, CargoItem, FactionSymbol, Ship, ShipNavStatus, ShipRole,
+    DEFAULT_ENV_FILE,
 };
+use spacetraders_client::automation::{find_nearest_market, get_current_market_prices};
+use spacetraders_client::{SpaceTradersClient, SpaceTradersError};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -15,8 +13,36 @@ async fn main() -> Result<()> {
     env_logger::init();
     debug!("Starting SpaceTraders client");
 
-    // Get agent details
-    match get_agent_details().await {
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("register") {
+        return register_agent(&args[2..]).await;
+    }
+
+    let client = SpaceTradersClient::from_env()?;
+
+    match args.get(1).map(String::as_str) {
+        Some("sell") => {
+            let [ship_symbol, waypoint_symbol, ..] = &args[2..] else {
+                anyhow::bail!("Usage: spacetraders-client sell <SHIP> <WAYPOINT>");
+            };
+            return handle_cargo_sale(&client, ship_symbol, waypoint_symbol).await;
+        }
+        Some("ship") => {
+            let [ship_symbol, ..] = &args[2..] else {
+                anyhow::bail!("Usage: spacetraders-client ship <SHIP>");
+            };
+            let ships = client.list_ships().await?;
+            let ship = ships
+                .iter()
+                .find(|s| &s.symbol == ship_symbol)
+                .ok_or_else(|| anyhow::anyhow!("Ship not found"))?;
+            return handle_ship_operations(&client, ship).await;
+        }
+        _ => {}
+    }
+
+    // Get agent details; the headquarters decides which system to browse
+    let home_system = match client.get_agent_details().await {
         Ok(response) => {
             println!("\nAgent Details:");
             println!("==============");
@@ -24,16 +50,29 @@ async fn main() -> Result<()> {
             println!("Credits: {}", response.data.credits);
             println!("Headquarters: {}", response.data.headquarters);
             println!("Ship Count: {}", response.data.ship_count);
+            Some(system_symbol_of(&response.data.headquarters).to_string())
         }
-        Err(e) => println!("Error getting agent details: {}", e),
-    }
+        Err(e) => {
+            println!("Error getting agent details: {}", e);
+            let token_invalid = e
+                .downcast_ref::<SpaceTradersError>()
+                .is_some_and(SpaceTradersError::is_token_invalid);
+            if token_invalid {
+                println!("The token was rejected; the server may have reset.");
+                println!("Run `spacetraders-client register <SYMBOL> <FACTION>` for a new agent.");
+                // Exit non-zero so scripts can detect the reset and re-register.
+                return Err(e);
+            }
+            None
+        }
+    };
 
     // Check contracts first
     println!("\nChecking Contracts:");
     println!("==================");
-    match list_contracts().await {
-        Ok(response) => {
-            for contract in &response.data {
+    match client.list_contracts().await {
+        Ok(contracts) => {
+            for contract in &contracts {
                 println!("\nContract ID: {}", contract.id);
                 println!("Type: {}", contract.contract_type);
                 println!("Faction: {}", contract.faction_symbol);
@@ -79,7 +118,7 @@ async fn main() -> Result<()> {
                     std::io::stdin().read_line(&mut input)?;
 
                     if input.trim().to_lowercase() == "y" {
-                        match accept_contract(&contract.id).await {
+                        match client.accept_contract(&contract.id).await {
                             Ok(accepted) => {
                                 println!("Successfully accepted contract!");
                                 println!(
@@ -98,60 +137,70 @@ async fn main() -> Result<()> {
     }
 
     // Then check shipyards
-    let system_symbol = "X1-PS34";
-    println!("\nChecking Shipyards in system {}:", system_symbol);
-    println!("=================================");
-
-    match find_shipyards(system_symbol).await {
-        Ok(response) => {
-            for waypoint in &response.data {
-                println!("\nWaypoint: {}", waypoint.symbol);
-                println!("Type: {}", waypoint.r#type);
-                println!("Location: ({}, {})", waypoint.x, waypoint.y);
-
-                match get_shipyard_details(system_symbol, &waypoint.symbol).await {
-                    Ok(shipyard) => {
-                        println!("\nAvailable ships at {}:", waypoint.symbol);
-                        if let Some(ships) = shipyard.data.ships {
-                            for ship in ships {
-                                println!("\nShip type: {}", ship.r#type);
-                                println!("Name: {}", ship.name);
-                                println!("Price: {} credits", ship.purchase_price);
-                                println!("Description: {}", ship.description);
-
-                                println!("\nWould you like to purchase this ship? (y/n)");
-                                let mut input = String::new();
-                                std::io::stdin().read_line(&mut input)?;
-
-                                if input.trim().to_lowercase() == "y" {
-                                    match purchase_ship(&ship.r#type, &waypoint.symbol).await {
-                                        Ok(purchase_response) => {
-                                            println!("Successfully purchased ship!");
-                                            println!("Ship details: {:?}", purchase_response.data);
+    if let Some(system_symbol) = home_system.as_deref() {
+        println!("\nChecking Shipyards in system {}:", system_symbol);
+        println!("=================================");
+
+        match client.find_shipyards(system_symbol).await {
+            Ok(shipyards) => {
+                for waypoint in &shipyards {
+                    println!("\nWaypoint: {}", waypoint.symbol);
+                    println!("Type: {}", waypoint.r#type);
+                    println!("Location: ({}, {})", waypoint.x, waypoint.y);
+
+                    match client
+                        .get_shipyard_details(system_symbol, &waypoint.symbol)
+                        .await
+                    {
+                        Ok(shipyard) => {
+                            println!("\nAvailable ships at {}:", waypoint.symbol);
+                            if let Some(ships) = shipyard.data.ships {
+                                for ship in ships {
+                                    println!("\nShip type: {}", ship.r#type);
+                                    println!("Name: {}", ship.name);
+                                    println!("Price: {} credits", ship.purchase_price);
+                                    println!("Description: {}", ship.description);
+
+                                    println!("\nWould you like to purchase this ship? (y/n)");
+                                    let mut input = String::new();
+                                    std::io::stdin().read_line(&mut input)?;
+
+                                    if input.trim().to_lowercase() == "y" {
+                                        match client
+                                            .purchase_ship(&ship.r#type, &waypoint.symbol)
+                                            .await
+                                        {
+                                            Ok(purchase_response) => {
+                                                println!("Successfully purchased ship!");
+                                                println!(
+                                                    "Ship details: {:?}",
+                                                    purchase_response.data
+                                                );
+                                            }
+                                            Err(e) => println!("Error purchasing ship: {}", e),
                                         }
-                                        Err(e) => println!("Error purchasing ship: {}", e),
                                     }
                                 }
+                            } else {
+                                println!("No ships currently available at this shipyard");
                             }
-                        } else {
-                            println!("No ships currently available at this shipyard");
                         }
+                        Err(e) => println!("Error getting shipyard details: {}", e),
                     }
-                    Err(e) => println!("Error getting shipyard details: {}", e),
                 }
             }
+            Err(e) => println!("Error finding shipyards: {}", e),
         }
-        Err(e) => println!("Error finding shipyards: {}", e),
     }
 
     println!("\nChecking Ships and Asteroids:");
     println!("===========================");
 
     // List current ships
-    match list_ships().await {
-        Ok(response) => {
+    match client.list_ships().await {
+        Ok(ships) => {
             info!("\nYour Ships:");
-            for ship in &response.data {
+            for ship in &ships {
                 debug!("Processing ship: {}", ship.symbol);
                 info!("\nShip Symbol: {}", ship.symbol);
                 println!("Registration:");
@@ -163,14 +212,12 @@ async fn main() -> Result<()> {
                 println!("  Status: {}", ship.nav.status);
                 println!("  System: {}", ship.nav.system_symbol);
                 println!("  Waypoint: {}", ship.nav.waypoint_symbol);
+                println!("  Flight mode: {}", ship.nav.flight_mode);
 
                 if let Some(route) = &ship.nav.route {
                     println!("\nRoute:");
-                    if let Some(departure) = &route.departure {
-                        println!(
-                            "  From: {} ({}, {})",
-                            departure.symbol, departure.x, departure.y
-                        );
+                    if let Some(origin) = &route.origin {
+                        println!("  From: {} ({}, {})", origin.symbol, origin.x, origin.y);
                     }
                     if let Some(destination) = &route.destination {
                         println!(
@@ -198,13 +245,13 @@ async fn main() -> Result<()> {
                 println!("\nFuel:");
                 println!("  {}/{} units", ship.fuel.current, ship.fuel.capacity);
 
-                if ship.nav.status != "IN_ORBIT" {
+                if ship.nav.status != ShipNavStatus::InOrbit {
                     println!("\nWould you like to put this ship into orbit? (y/n)");
                     let mut input = String::new();
                     std::io::stdin().read_line(&mut input)?;
 
                     if input.trim().to_lowercase() == "y" {
-                        match orbit_ship(&ship.symbol).await {
+                        match client.orbit_ship(&ship.symbol).await {
                             Ok(orbit_response) => {
                                 println!("Ship successfully entered orbit!");
                                 println!("New status: {}", orbit_response.data.nav.status);
@@ -215,7 +262,7 @@ async fn main() -> Result<()> {
                 }
 
                 // Handle mining operations
-                handle_mining_operations(ship).await?;
+                handle_mining_operations(&client, ship).await?;
 
                 println!("\n----------");
             }
@@ -226,30 +273,64 @@ async fn main() -> Result<()> {
         }
     }
 
-    // Find asteroids in the system
-    let system_symbol = "X1-PS34"; // Your current system
-    match find_asteroids(system_symbol).await {
-        Ok(response) => {
-            println!("\nEngineered Asteroids in system:");
-            for asteroid in &response.data {
-                println!("\nAsteroid: {}", asteroid.symbol);
-                println!("Type: {}", asteroid.r#type);
-                println!("Location: ({}, {})", asteroid.x, asteroid.y);
+    // Find asteroids in the home system
+    if let Some(system_symbol) = home_system.as_deref() {
+        match client.find_asteroids(system_symbol).await {
+            Ok(asteroids) => {
+                println!("\nEngineered Asteroids in system:");
+                for asteroid in &asteroids {
+                    println!("\nAsteroid: {}", asteroid.symbol);
+                    println!("Type: {}", asteroid.r#type);
+                    println!("Location: ({}, {})", asteroid.x, asteroid.y);
+                }
             }
+            Err(e) => println!("Error finding asteroids: {}", e),
         }
-        Err(e) => println!("Error finding asteroids: {}", e),
     }
 
     Ok(())
 }
 
-async fn handle_cargo_sale(ship_symbol: &str, waypoint_symbol: &str) -> Result<()> {
+// Registers a new agent and stores its token so later runs pick it up from .env
+async fn register_agent(args: &[String]) -> Result<()> {
+    let (symbol, faction) = match args {
+        [symbol, faction, ..] => (symbol, faction),
+        _ => anyhow::bail!("Usage: spacetraders-client register <SYMBOL> <FACTION> [EMAIL]"),
+    };
+    let email = args.get(2).map(String::as_str);
+
+    let faction = FactionSymbol::from(faction.to_uppercase().as_str());
+
+    let client = SpaceTradersClient::account_from_env();
+    let response = client.register(symbol, &faction, email).await?;
+    let registration = response.data;
+
+    save_token(DEFAULT_ENV_FILE, &registration.token)?;
+
+    println!("\nRegistered Agent:");
+    println!("=================");
+    println!("Symbol: {}", registration.agent.symbol);
+    println!("Faction: {}", registration.faction.name);
+    println!("Headquarters: {}", registration.agent.headquarters);
+    println!("Credits: {}", registration.agent.credits);
+    println!("Starting ship: {}", registration.ship.symbol);
+    println!("Starting contract: {}", registration.contract.id);
+    println!("Token saved to {}", DEFAULT_ENV_FILE);
+
+    Ok(())
+}
+
+async fn handle_cargo_sale(
+    client: &SpaceTradersClient,
+    ship_symbol: &str,
+    waypoint_symbol: &str,
+) -> Result<()> {
     debug!(
         "Starting cargo sale for ship {} at waypoint {}",
         ship_symbol, waypoint_symbol
     );
     // First dock the ship
-    match dock_ship(ship_symbol).await {
+    match client.dock_ship(ship_symbol).await {
         Ok(_) => println!("Ship docked successfully."),
         Err(e) => {
             println!("Error docking ship: {}", e);
@@ -258,12 +339,8 @@ async fn handle_cargo_sale(ship_symbol: &str, waypoint_symbol: &str) -> Result<(
     }
 
     // Get market data
-    let system_symbol = waypoint_symbol
-        .split('-')
-        .take(2)
-        .collect::<Vec<_>>()
-        .join("-");
-    match get_market_data(&system_symbol, waypoint_symbol).await {
+    let system_symbol = system_symbol_of(waypoint_symbol);
+    match client.get_market_data(system_symbol, waypoint_symbol).await {
         Ok(market_response) => {
             println!("\nMarket prices at {}:", waypoint_symbol);
             for good in &market_response.data.trade_goods {
@@ -274,7 +351,7 @@ async fn handle_cargo_sale(ship_symbol: &str, waypoint_symbol: &str) -> Result<(
             }
 
             // Get ship's cargo
-            match get_ship_cargo(ship_symbol).await {
+            match client.get_ship_cargo(ship_symbol).await {
                 Ok(cargo_response) => {
                     println!(
                         "\nShip cargo ({}/{} units):",
@@ -289,7 +366,10 @@ async fn handle_cargo_sale(ship_symbol: &str, waypoint_symbol: &str) -> Result<(
                         std::io::stdin().read_line(&mut input)?;
 
                         if input.trim().to_lowercase() == "y" {
-                            match sell_cargo_item(ship_symbol, &item.symbol, item.units).await {
+                            match client
+                                .sell_cargo_item(ship_symbol, &item.symbol, item.units)
+                                .await
+                            {
                                 Ok(sell_response) => {
                                     println!(
                                         "Sold {} units of {} for {} credits",
@@ -317,13 +397,16 @@ async fn handle_cargo_sale(ship_symbol: &str, waypoint_symbol: &str) -> Result<(
 }
 
 // Clean up the sell_selected_cargo function to show market prices
-async fn sell_selected_cargo(ship_symbol: &str, inventory: &[CargoItem]) -> Result<()> {
+async fn sell_selected_cargo(
+    client: &SpaceTradersClient,
+    ship_symbol: &str,
+    inventory: &[CargoItem],
+) -> Result<()> {
     debug!("Starting selective cargo sale for ship {}", ship_symbol);
 
     // First get ship details to know current location
-    let ships_response = list_ships().await?;
-    let ship = ships_response
-        .data
+    let ships = client.list_ships().await?;
+    let ship = ships
         .iter()
         .find(|s| s.symbol == ship_symbol)
         .ok_or_else(|| anyhow::anyhow!("Ship not found"))?;
@@ -332,25 +415,27 @@ async fn sell_selected_cargo(ship_symbol: &str, inventory: &[CargoItem]) -> Resu
     let current_waypoint = &ship.nav.waypoint_symbol;
 
     // Find nearest market
-    if let Some(market_waypoint) = find_nearest_market(system_symbol, current_waypoint).await? {
+    if let Some(market_waypoint) =
+        find_nearest_market(client, system_symbol, current_waypoint).await?
+    {
         if market_waypoint.as_str() != current_waypoint {
             info!("Navigating to market at {}", market_waypoint);
 
-            if ship.nav.status == "DOCKED" {
-                orbit_ship(ship_symbol).await?;
+            if ship.nav.status == ShipNavStatus::Docked {
+                client.orbit_ship(ship_symbol).await?;
             }
 
-            navigate_ship(ship_symbol, &market_waypoint).await?;
+            client.navigate_ship(ship_symbol, &market_waypoint).await?;
             tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
         }
 
-        match dock_ship(ship_symbol).await {
+        match client.dock_ship(ship_symbol).await {
             Ok(_) => {
                 info!("Ship docked successfully at market");
 
                 // Get market prices first
                 if let Ok(market_prices) =
-                    get_current_market_prices(system_symbol, &market_waypoint).await
+                    get_current_market_prices(client, system_symbol, &market_waypoint).await
                 {
                     for item in inventory {
                         if let Some((_, price)) = market_prices
@@ -387,7 +472,8 @@ async fn sell_selected_cargo(ship_symbol: &str, inventory: &[CargoItem]) -> Resu
                                         "Attempting to sell {} units of {}",
                                         units_to_sell, item.symbol
                                     );
-                                    match sell_cargo_item(ship_symbol, &item.symbol, units_to_sell)
+                                    match client
+                                        .sell_cargo_item(ship_symbol, &item.symbol, units_to_sell)
                                         .await
                                     {
                                         Ok(sell_response) => {
@@ -427,37 +513,9 @@ async fn sell_selected_cargo(ship_symbol: &str, inventory: &[CargoItem]) -> Resu
     Ok(())
 }
 
-async fn find_nearest_market(
-    system_symbol: &str,
-    current_waypoint: &str,
-) -> Result<Option<String>> {
-    // Get waypoint details to check for marketplace
-    match get_waypoint_details(system_symbol, current_waypoint).await {
-        Ok(response) => {
-            let has_marketplace = response
-                .data
-                .traits
-                .iter()
-                .any(|trait_| trait_.symbol == "MARKETPLACE");
-
-            if has_marketplace {
-                return Ok(Some(current_waypoint.to_string()));
-            }
-        }
-        Err(e) => {
-            error!("Failed to get waypoint details: {}", e);
-        }
-    }
-
-    // If current waypoint doesn't have a marketplace, look for nearest one
-    // You would need to implement the logic to find other waypoints with markets
-    // For now, we'll return None to indicate no market was found
-    Ok(None)
-}
-
-async fn handle_mining_operations(ship: &Ship) -> Result<()> {
+async fn handle_mining_operations(client: &SpaceTradersClient, ship: &Ship) -> Result<()> {
     debug!("Starting mining operations for ship: {}", ship.symbol);
-    if ship.registration.role == "EXCAVATOR" {
+    if ship.registration.role == ShipRole::Excavator {
         info!("\nMining Operations for {}", ship.symbol);
         info!("Current status: {}", ship.nav.status);
 
@@ -469,18 +527,19 @@ async fn handle_mining_operations(ship: &Ship) -> Result<()> {
 
             if input.trim().to_lowercase() == "y" {
                 // Check if the ship is docked
-                if ship.nav.status != "DOCKED" {
+                if ship.nav.status != ShipNavStatus::Docked {
                     println!("Ship needs to be docked to sell cargo.");
                     println!("Would you like to dock the ship? (y/n)");
                     let mut input = String::new();
                     std::io::stdin().read_line(&mut input)?;
 
                     if input.trim().to_lowercase() == "y" {
-                        match dock_ship(&ship.symbol).await {
+                        match client.dock_ship(&ship.symbol).await {
                             Ok(_) => {
                                 println!("Ship docked successfully.");
                                 // Pass the inventory to the function
-                                sell_selected_cargo(&ship.symbol, &ship.cargo.inventory).await?;
+                                sell_selected_cargo(client, &ship.symbol, &ship.cargo.inventory)
+                                    .await?;
                             }
                             Err(e) => {
                                 error!("Failed to dock ship: {}", e);
@@ -494,13 +553,13 @@ async fn handle_mining_operations(ship: &Ship) -> Result<()> {
                     }
                 } else {
                     // Pass the inventory when calling from docked state
-                    sell_selected_cargo(&ship.symbol, &ship.cargo.inventory).await?;
+                    sell_selected_cargo(client, &ship.symbol, &ship.cargo.inventory).await?;
                 }
             }
         }
 
         // Rest of the mining operations code remains the same
-        if ship.nav.status == "IN_ORBIT" {
+        if ship.nav.status == ShipNavStatus::InOrbit {
             println!("\nWould you like to begin mining operations? (y/n)");
             let mut input = String::new();
             std::io::stdin().read_line(&mut input)?;
@@ -517,29 +576,7 @@ async fn handle_mining_operations(ship: &Ship) -> Result<()> {
     Ok(())
 }
 
-// current market prices - this will be helpful for selling decisions
-async fn get_current_market_prices(
-    system_symbol: &str,
-    waypoint_symbol: &str,
-) -> Result<Vec<(String, i32)>> {
-    match get_market_data(system_symbol, waypoint_symbol).await {
-        Ok(market_response) => {
-            let prices: Vec<(String, i32)> = market_response
-                .data
-                .trade_goods
-                .iter()
-                .map(|good| (good.symbol.clone(), good.sell_price))
-                .collect();
-            Ok(prices)
-        }
-        Err(e) => {
-            error!("Failed to get market prices: {}", e);
-            Err(e)
-        }
-    }
-}
-
-async fn handle_ship_operations(ship: &Ship) -> Result<()> {
+async fn handle_ship_operations(client: &SpaceTradersClient, ship: &Ship) -> Result<()> {
     debug!("Starting operations for ship: {}", ship.symbol);
 
     // Display ship info
@@ -568,17 +605,18 @@ async fn handle_ship_operations(ship: &Ship) -> Result<()> {
         std::io::stdin().read_line(&mut input)?;
 
         if input.trim().to_lowercase() == "y" {
-            if ship.nav.status != "DOCKED" {
+            if ship.nav.status != ShipNavStatus::Docked {
                 println!("Ship needs to be docked to manage cargo.");
                 println!("Would you like to dock the ship? (y/n)");
                 let mut input = String::new();
                 std::io::stdin().read_line(&mut input)?;
 
                 if input.trim().to_lowercase() == "y" {
-                    match dock_ship(&ship.symbol).await {
+                    match client.dock_ship(&ship.symbol).await {
                         Ok(_) => {
                             println!("Ship docked successfully.");
-                            sell_selected_cargo(&ship.symbol, &ship.cargo.inventory).await?;
+                            sell_selected_cargo(client, &ship.symbol, &ship.cargo.inventory)
+                                .await?;
                         }
                         Err(e) => {
                             error!("Failed to dock ship: {}", e);
@@ -589,7 +627,7 @@ async fn handle_ship_operations(ship: &Ship) -> Result<()> {
                 }
             } else {
                 // If already docked, manage cargo
-                sell_selected_cargo(&ship.symbol, &ship.cargo.inventory).await?;
+                sell_selected_cargo(client, &ship.symbol, &ship.cargo.inventory).await?;
             }
         }
     }